@@ -0,0 +1,69 @@
+// Package api exposes the REST API specification shipped with the authorization server
+package api
+
+import (
+	_ "embed"
+	"encoding/json"
+	"fmt"
+	"os"
+
+	"gopkg.in/yaml.v3"
+)
+
+//go:embed openapi.yaml
+var openAPIYAML []byte
+
+// OpenAPIYAML returns the raw OpenAPI 3 specification as YAML
+func OpenAPIYAML() []byte {
+	return openAPIYAML
+}
+
+// OpenAPIJSON returns the OpenAPI 3 specification converted to JSON
+func OpenAPIJSON() ([]byte, error) {
+	var doc interface{}
+	if err := yaml.Unmarshal(openAPIYAML, &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI spec: %w", err)
+	}
+
+	data, err := json.MarshalIndent(normalizeYAML(doc), "", "  ")
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode OpenAPI spec as JSON: %w", err)
+	}
+	return data, nil
+}
+
+// DumpOpenAPI writes the OpenAPI JSON specification to path
+func DumpOpenAPI(path string) error {
+	data, err := OpenAPIJSON()
+	if err != nil {
+		return err
+	}
+	if err := os.WriteFile(path, data, 0644); err != nil {
+		return fmt.Errorf("failed to write OpenAPI spec to %s: %w", path, err)
+	}
+	return nil
+}
+
+// normalizeYAML converts YAML mappings with non-string keys into JSON-compatible maps
+func normalizeYAML(v interface{}) interface{} {
+	switch val := v.(type) {
+	case map[string]interface{}:
+		for k, item := range val {
+			val[k] = normalizeYAML(item)
+		}
+		return val
+	case map[interface{}]interface{}:
+		out := make(map[string]interface{}, len(val))
+		for k, item := range val {
+			out[fmt.Sprint(k)] = normalizeYAML(item)
+		}
+		return out
+	case []interface{}:
+		for i, item := range val {
+			val[i] = normalizeYAML(item)
+		}
+		return val
+	default:
+		return v
+	}
+}
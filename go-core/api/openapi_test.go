@@ -0,0 +1,35 @@
+package api
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestDumpOpenAPI_WritesValidJSON(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "openapi.json")
+	require.NoError(t, DumpOpenAPI(path))
+
+	data, err := os.ReadFile(path)
+	require.NoError(t, err)
+
+	var spec map[string]interface{}
+	require.NoError(t, json.Unmarshal(data, &spec))
+
+	assert.Equal(t, "3.0.3", spec["openapi"])
+
+	paths, ok := spec["paths"].(map[string]interface{})
+	require.True(t, ok, "spec should contain a paths object")
+	for _, p := range []string{"/health", "/authorization/check", "/policies", "/policies/export", "/policies/import"} {
+		assert.Contains(t, paths, p)
+	}
+}
+
+func TestDumpOpenAPI_InvalidPath(t *testing.T) {
+	err := DumpOpenAPI(filepath.Join(t.TempDir(), "missing", "openapi.json"))
+	assert.Error(t, err)
+}
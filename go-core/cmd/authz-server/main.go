@@ -14,6 +14,7 @@ import (
 	"go.uber.org/zap"
 	"go.uber.org/zap/zapcore"
 
+	"github.com/authz-engine/go-core/api"
 	"github.com/authz-engine/go-core/internal/api/rest"
 	"github.com/authz-engine/go-core/internal/engine"
 	"github.com/authz-engine/go-core/internal/policy"
@@ -45,6 +46,7 @@ func main() {
 		enableREST      = flag.Bool("enable-rest", true, "Enable REST API server")
 		enableCORS      = flag.Bool("enable-cors", true, "Enable CORS for REST API")
 		gracefulTimeout = flag.Duration("shutdown-timeout", 30*time.Second, "Graceful shutdown timeout")
+		dumpOpenAPI     = flag.String("dump-openapi", "", "Write the OpenAPI JSON spec to this path and exit")
 	)
 	flag.Parse()
 
@@ -56,6 +58,16 @@ func main() {
 		os.Exit(0)
 	}
 
+	// Dump OpenAPI spec and exit (used by CI for client generation)
+	if *dumpOpenAPI != "" {
+		if err := api.DumpOpenAPI(*dumpOpenAPI); err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to dump OpenAPI spec: %v\n", err)
+			os.Exit(1)
+		}
+		fmt.Printf("OpenAPI spec written to %s\n", *dumpOpenAPI)
+		os.Exit(0)
+	}
+
 	// Initialize logger
 	logger, err := initLogger(*logLevel, *logFormat)
 	if err != nil {
@@ -12,6 +12,7 @@ import (
 	"github.com/google/uuid"
 	"go.uber.org/zap"
 
+	"github.com/authz-engine/go-core/internal/server/middleware"
 	"github.com/authz-engine/go-core/pkg/types"
 )
 
@@ -49,6 +50,7 @@ func (s *Server) authorizationCheckHandler(w http.ResponseWriter, r *http.Reques
 	// Convert to internal types
 	internalReq := &types.CheckRequest{
 		RequestID: uuid.New().String(),
+		TenantID:  middleware.TenantFromContext(r.Context()),
 		Principal: req.Principal.ToInternalPrincipal(),
 		Resource:  req.Resource.ToInternalResource(),
 		Actions:   []string{req.Action},
@@ -149,6 +151,7 @@ func (s *Server) batchCheckResourcesHandler(w http.ResponseWriter, r *http.Reque
 	for i, res := range req.Resources {
 		internalReq := &types.CheckRequest{
 			RequestID: uuid.New().String(),
+			TenantID:  middleware.TenantFromContext(r.Context()),
 			Principal: req.Principal.ToInternalPrincipal(),
 			Resource:  res.Resource.ToInternalResource(),
 			Actions:   []string{res.Action},
@@ -277,6 +280,7 @@ func (s *Server) allowedActionsHandler(w http.ResponseWriter, r *http.Request) {
 	for _, action := range commonActions {
 		internalReq := &types.CheckRequest{
 			RequestID: uuid.New().String(),
+			TenantID:  middleware.TenantFromContext(r.Context()),
 			Principal: principal,
 			Resource:  resource,
 			Actions:   []string{action},
@@ -2,13 +2,13 @@
 package auth
 
 import (
-	"crypto/rand"
 	"crypto/sha256"
 	"encoding/base64"
 	"fmt"
 	"strings"
 	"time"
 
+	"github.com/authz-engine/go-core/pkg/crypto/rng"
 	"github.com/google/uuid"
 )
 
@@ -58,8 +58,8 @@ type APIKeyResponse struct {
 // GenerateAPIKey creates a new API key with cryptographic randomness
 func GenerateAPIKey() (string, error) {
 	// Generate 32 random bytes
-	randomBytes := make([]byte, APIKeyLength)
-	if _, err := rand.Read(randomBytes); err != nil {
+	randomBytes, err := rng.SecureBytes(APIKeyLength)
+	if err != nil {
 		return "", fmt.Errorf("failed to generate random bytes: %w", err)
 	}
 
@@ -1,11 +1,12 @@
 package apikey
 
 import (
-	"crypto/rand"
 	"crypto/sha256"
 	"encoding/base64"
 	"fmt"
 	"strings"
+
+	"github.com/authz-engine/go-core/pkg/crypto/rng"
 )
 
 const (
@@ -28,8 +29,8 @@ func NewGenerator() *Generator {
 // Returns the plain key and its SHA-256 hash
 func (g *Generator) Generate() (plainKey string, keyHash string, err error) {
 	// Generate 32 bytes of cryptographically secure random data
-	randomBytes := make([]byte, APIKeyBytes)
-	if _, err := rand.Read(randomBytes); err != nil {
+	randomBytes, err := rng.SecureBytes(APIKeyBytes)
+	if err != nil {
 		return "", "", fmt.Errorf("generate random bytes: %w", err)
 	}
 
@@ -12,6 +12,7 @@ import (
 
 	"github.com/authz-engine/go-core/internal/agent"
 	"github.com/authz-engine/go-core/internal/cache"
+	"github.com/authz-engine/go-core/pkg/crypto/rng"
 	"github.com/authz-engine/go-core/pkg/types"
 	"github.com/golang-jwt/jwt/v5"
 	"github.com/redis/go-redis/v9"
@@ -209,8 +210,8 @@ func (i *JWTIssuer) IssueToken(ctx context.Context, agentID string, roles []stri
 // GenerateRefreshToken generates a new refresh token and stores it
 func (i *JWTIssuer) GenerateRefreshToken(ctx context.Context, agentID string, accessTokenJTI string) (string, error) {
 	// Generate secure random token (256-bit)
-	tokenBytes := make([]byte, 32)
-	if _, err := rand.Read(tokenBytes); err != nil {
+	tokenBytes, err := rng.SecureBytes(32)
+	if err != nil {
 		return "", fmt.Errorf("generate random token: %w", err)
 	}
 
@@ -9,6 +9,8 @@ import (
 	"encoding/pem"
 	"fmt"
 	"io"
+
+	"github.com/authz-engine/go-core/pkg/crypto/rng"
 )
 
 // AESKeyEncryptor implements KeyEncryptor using AES-256-GCM
@@ -94,8 +96,8 @@ func ParsePublicKeyPEM(pemStr string) (interface{}, error) {
 
 // GenerateAESKey generates a random 32-byte AES key
 func GenerateAESKey() ([]byte, error) {
-	key := make([]byte, 32)
-	if _, err := rand.Read(key); err != nil {
+	key, err := rng.SecureBytes(32)
+	if err != nil {
 		return nil, fmt.Errorf("failed to generate AES key: %w", err)
 	}
 	return key, nil
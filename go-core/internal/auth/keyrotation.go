@@ -2,14 +2,15 @@ package auth
 
 import (
 	"context"
-	"crypto/rand"
 	"crypto/rsa"
 	"crypto/x509"
 	"database/sql"
 	"encoding/pem"
 	"fmt"
+	"sync"
 	"time"
 
+	"github.com/authz-engine/go-core/pkg/crypto/rng"
 	"github.com/google/uuid"
 )
 
@@ -47,6 +48,12 @@ type KeyRotationManager struct {
 	db            *sql.DB
 	encryptor     KeyEncryptor
 	gracePeriod   time.Duration
+
+	// Master key rewrap state (see RewrapAll)
+	mu         sync.RWMutex
+	previous   KeyEncryptor // old master key, kept for reads until every key is rewrapped
+	rewrapping bool
+	progress   *RewrapProgress
 }
 
 // KeyEncryptor defines the interface for key encryption/decryption
@@ -67,7 +74,7 @@ func NewKeyRotationManager(db *sql.DB, encryptor KeyEncryptor) *KeyRotationManag
 // GenerateNewKey generates a new RSA key pair
 func (krm *KeyRotationManager) GenerateNewKey(ctx context.Context) (*SigningKey, error) {
 	// Generate RSA key pair
-	privateKey, err := rsa.GenerateKey(rand.Reader, DefaultKeySize)
+	privateKey, err := rsa.GenerateKey(rng.Reader(), DefaultKeySize)
 	if err != nil {
 		return nil, fmt.Errorf("failed to generate RSA key: %w", err)
 	}
@@ -80,7 +87,8 @@ func (krm *KeyRotationManager) GenerateNewKey(ctx context.Context) (*SigningKey,
 	})
 
 	// Encrypt private key
-	encryptedPrivateKey, err := krm.encryptor.Encrypt(privateKeyPEM)
+	current, _ := krm.encryptors()
+	encryptedPrivateKey, err := current.Encrypt(privateKeyPEM)
 	if err != nil {
 		return nil, fmt.Errorf("failed to encrypt private key: %w", err)
 	}
@@ -254,8 +262,12 @@ func (krm *KeyRotationManager) ExpireOldKeys(ctx context.Context) (int, error) {
 
 // decryptPrivateKey decrypts and parses the private key
 func (krm *KeyRotationManager) decryptPrivateKey(key *SigningKey) error {
-	// Decrypt
-	privateKeyPEM, err := krm.encryptor.Decrypt(key.PrivateKeyEncrypted)
+	// Decrypt, falling back to the old master key for keys not yet rewrapped
+	current, previous := krm.encryptors()
+	privateKeyPEM, err := current.Decrypt(key.PrivateKeyEncrypted)
+	if err != nil && previous != nil {
+		privateKeyPEM, err = previous.Decrypt(key.PrivateKeyEncrypted)
+	}
 	if err != nil {
 		return fmt.Errorf("failed to decrypt private key: %w", err)
 	}
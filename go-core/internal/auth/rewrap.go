@@ -0,0 +1,201 @@
+package auth
+
+import (
+	"context"
+	"errors"
+	"fmt"
+)
+
+// RewrapProgress reports the state of a master key rewrap
+type RewrapProgress struct {
+	Total          int      // Signing keys found when the rewrap started
+	Rewrapped      int      // Keys re-encrypted under the new master key by this run
+	AlreadyCurrent int      // Keys already under the new master key (e.g. from an interrupted run)
+	Remaining      []string // KIDs still encrypted under the old master key
+	Done           bool     // Every key is under the new master key
+}
+
+func (p *RewrapProgress) clone() *RewrapProgress {
+	c := *p
+	c.Remaining = append([]string(nil), p.Remaining...)
+	return &c
+}
+
+// RewrapAll re-encrypts every stored private key under newEncryptor after a master key change.
+//
+// New keys are encrypted with newEncryptor as soon as the rewrap starts, and reads try
+// newEncryptor first and fall back to the old master key until every key has been rewrapped,
+// so signing keeps working throughout. Progress is tracked per KID: a key that already
+// decrypts under newEncryptor is skipped, so an interrupted rewrap is resumed by calling
+// RewrapAll again with the same encryptor (on a manager still built with the old one after
+// a restart). newEncryptor must authenticate ciphertexts, as AESKeyEncryptor does.
+//
+// Run it in a goroutine and poll RewrapStatus to rewrap in the background.
+func (krm *KeyRotationManager) RewrapAll(ctx context.Context, newEncryptor KeyEncryptor) (*RewrapProgress, error) {
+	if newEncryptor == nil {
+		return nil, fmt.Errorf("new encryptor is required")
+	}
+
+	krm.mu.Lock()
+	if krm.rewrapping {
+		krm.mu.Unlock()
+		return nil, fmt.Errorf("rewrap already in progress")
+	}
+	if krm.encryptor != newEncryptor {
+		if krm.previous != nil {
+			krm.mu.Unlock()
+			return nil, fmt.Errorf("previous rewrap is incomplete, resume it with the same encryptor first")
+		}
+		krm.previous = krm.encryptor
+		krm.encryptor = newEncryptor
+	}
+	oldEncryptor := krm.previous
+	krm.rewrapping = true
+	krm.progress = &RewrapProgress{}
+	krm.mu.Unlock()
+
+	defer func() {
+		krm.mu.Lock()
+		krm.rewrapping = false
+		krm.mu.Unlock()
+	}()
+
+	keys, err := krm.loadEncryptedKeys(ctx)
+	if err != nil {
+		return krm.RewrapStatus(), err
+	}
+
+	krm.updateProgress(func(p *RewrapProgress) {
+		p.Total = len(keys)
+		for _, k := range keys {
+			p.Remaining = append(p.Remaining, k.KID)
+		}
+	})
+
+	var errs []error
+	for _, k := range keys {
+		if err := ctx.Err(); err != nil {
+			return krm.RewrapStatus(), err
+		}
+
+		alreadyCurrent, err := krm.rewrapKey(ctx, k, oldEncryptor, newEncryptor)
+		if err != nil {
+			errs = append(errs, err)
+			continue
+		}
+
+		krm.updateProgress(func(p *RewrapProgress) {
+			if alreadyCurrent {
+				p.AlreadyCurrent++
+			} else {
+				p.Rewrapped++
+			}
+			for i, kid := range p.Remaining {
+				if kid == k.KID {
+					p.Remaining = append(p.Remaining[:i], p.Remaining[i+1:]...)
+					break
+				}
+			}
+		})
+	}
+
+	if len(errs) > 0 {
+		// Keep the old master key for reads; a later RewrapAll picks up the remaining keys
+		return krm.RewrapStatus(), fmt.Errorf("failed to rewrap %d signing keys: %w", len(errs), errors.Join(errs...))
+	}
+
+	krm.mu.Lock()
+	krm.previous = nil
+	krm.progress.Done = true
+	krm.mu.Unlock()
+
+	return krm.RewrapStatus(), nil
+}
+
+// RewrapStatus returns the progress of the current or last rewrap, or nil if none has run
+func (krm *KeyRotationManager) RewrapStatus() *RewrapProgress {
+	krm.mu.RLock()
+	defer krm.mu.RUnlock()
+
+	if krm.progress == nil {
+		return nil
+	}
+	return krm.progress.clone()
+}
+
+// rewrapKey moves one key to the new master key, reporting whether it was already there
+func (krm *KeyRotationManager) rewrapKey(ctx context.Context, key *SigningKey, oldEncryptor, newEncryptor KeyEncryptor) (bool, error) {
+	if _, err := newEncryptor.Decrypt(key.PrivateKeyEncrypted); err == nil {
+		return true, nil
+	}
+	if oldEncryptor == nil {
+		return false, fmt.Errorf("key %s: does not decrypt under the current master key", key.KID)
+	}
+
+	plaintext, err := oldEncryptor.Decrypt(key.PrivateKeyEncrypted)
+	if err != nil {
+		return false, fmt.Errorf("key %s: failed to decrypt with old master key: %w", key.KID, err)
+	}
+
+	rewrapped, err := newEncryptor.Encrypt(plaintext)
+	if err != nil {
+		return false, fmt.Errorf("key %s: failed to encrypt with new master key: %w", key.KID, err)
+	}
+
+	// Only replace the ciphertext we decrypted, so a concurrent change is never overwritten
+	query := `
+		UPDATE signing_keys
+		SET private_key_encrypted = $1
+		WHERE kid = $2 AND private_key_encrypted = $3
+	`
+	result, err := krm.db.ExecContext(ctx, query, rewrapped, key.KID, key.PrivateKeyEncrypted)
+	if err != nil {
+		return false, fmt.Errorf("key %s: failed to store rewrapped key: %w", key.KID, err)
+	}
+	if count, _ := result.RowsAffected(); count == 0 {
+		return false, fmt.Errorf("key %s: changed during rewrap", key.KID)
+	}
+
+	return false, nil
+}
+
+// loadEncryptedKeys reads the KID and ciphertext of every stored signing key
+func (krm *KeyRotationManager) loadEncryptedKeys(ctx context.Context) ([]*SigningKey, error) {
+	query := `
+		SELECT kid, private_key_encrypted
+		FROM signing_keys
+		ORDER BY kid
+	`
+
+	rows, err := krm.db.QueryContext(ctx, query)
+	if err != nil {
+		return nil, fmt.Errorf("failed to query signing keys: %w", err)
+	}
+	defer rows.Close()
+
+	var keys []*SigningKey
+	for rows.Next() {
+		var key SigningKey
+		if err := rows.Scan(&key.KID, &key.PrivateKeyEncrypted); err != nil {
+			return nil, fmt.Errorf("failed to scan key: %w", err)
+		}
+		keys = append(keys, &key)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("failed to read signing keys: %w", err)
+	}
+
+	return keys, nil
+}
+
+func (krm *KeyRotationManager) encryptors() (current, previous KeyEncryptor) {
+	krm.mu.RLock()
+	defer krm.mu.RUnlock()
+	return krm.encryptor, krm.previous
+}
+
+func (krm *KeyRotationManager) updateProgress(update func(p *RewrapProgress)) {
+	krm.mu.Lock()
+	defer krm.mu.Unlock()
+	update(krm.progress)
+}
@@ -0,0 +1,87 @@
+package cel
+
+import (
+	"encoding/json"
+)
+
+// Evaluation variables are normalized before they reach CEL so conditions see the
+// type a policy author expects, whatever transport the request arrived on:
+//
+//   - bool and string values pass through unchanged; strings are never parsed into numbers
+//   - Go integers map to CEL int (or uint for unsigned types) as-is
+//   - floating-point values stay double, including whole numbers such as JSON-decoded 18,
+//     so `R.attr.amount * 1.1` and `R.attr.amount / 3` keep double semantics
+//   - json.Number becomes int when written as an integer, otherwise double
+//   - nested map[string]interface{} and []interface{} values are normalized recursively
+//
+// Ordering comparisons across int, uint and double are enabled in the environment and
+// equality is heterogeneous, so a double age of 18 satisfies `P.attr.age == 18` and
+// `P.attr.age >= 18` without any rewriting.
+
+// normalizeMap returns m with all values normalized, copying only when a value changes
+func normalizeMap(m map[string]interface{}) (map[string]interface{}, bool) {
+	var out map[string]interface{}
+
+	for k, v := range m {
+		nv, changed := normalizeValue(v)
+		if !changed {
+			continue
+		}
+		if out == nil {
+			out = make(map[string]interface{}, len(m))
+			for k2, v2 := range m {
+				out[k2] = v2
+			}
+		}
+		out[k] = nv
+	}
+
+	if out == nil {
+		return m, false
+	}
+	return out, true
+}
+
+// normalizeList returns l with all elements normalized, copying only when an element changes
+func normalizeList(l []interface{}) ([]interface{}, bool) {
+	var out []interface{}
+
+	for i, v := range l {
+		nv, changed := normalizeValue(v)
+		if !changed {
+			continue
+		}
+		if out == nil {
+			out = make([]interface{}, len(l))
+			copy(out, l)
+		}
+		out[i] = nv
+	}
+
+	if out == nil {
+		return l, false
+	}
+	return out, true
+}
+
+// normalizeValue applies the coercion rules to a single value
+func normalizeValue(v interface{}) (interface{}, bool) {
+	switch x := v.(type) {
+	case float32:
+		return float64(x), true
+	case json.Number:
+		if i, err := x.Int64(); err == nil {
+			return i, true
+		}
+		if f, err := x.Float64(); err == nil {
+			return f, true
+		}
+		return x.String(), true
+	case map[string]interface{}:
+		return normalizeMap(x)
+	case []interface{}:
+		return normalizeList(x)
+	default:
+		return v, false
+	}
+}
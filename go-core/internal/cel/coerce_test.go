@@ -0,0 +1,90 @@
+package cel
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+)
+
+// decodeJSON decodes a request body the way the REST and gRPC layers do
+func decodeJSON(t *testing.T, body string) map[string]interface{} {
+	t.Helper()
+	var m map[string]interface{}
+	if err := json.Unmarshal([]byte(body), &m); err != nil {
+		t.Fatalf("Failed to decode JSON: %v", err)
+	}
+	return m
+}
+
+func TestEngine_Evaluate_ContextTypeCoercion(t *testing.T) {
+	engine, err := NewEngine()
+	if err != nil {
+		t.Fatalf("Failed to create engine: %v", err)
+	}
+
+	attrs := decodeJSON(t, `{"age": 21, "score": 1.5, "verified": true, "zip": "01234", "limits": {"max": 10}, "levels": [1, 2, 3]}`)
+	ctx := &EvalContext{
+		Principal: map[string]interface{}{"id": "user-1", "attr": attrs},
+		Resource:  map[string]interface{}{"kind": "invoice", "attr": decodeJSON(t, `{"amount": 100}`)},
+		Context:   decodeJSON(t, `{"retries": 3, "mfa": false}`),
+	}
+
+	tests := []struct {
+		name string
+		expr string
+		want bool
+	}{
+		{"whole number compares with int literal", `P.attr.age > 18`, true},
+		{"whole number equals int literal", `P.attr.age == 21`, true},
+		{"whole number multiplies as double", `R.attr.amount * 1.1 > 100`, true},
+		{"whole number scales as double", `P.attr.age * 1.1 > 23`, true},
+		{"whole number divides as double", `P.attr.age / 2.0 == 10.5`, true},
+		{"whole number keeps double type", `type(R.attr.amount) == double`, true},
+		{"fractional number compares with int literal", `P.attr.score > 1`, true},
+		{"fractional number stays double", `P.attr.score == 1.5`, true},
+		{"boolean stays boolean", `P.attr.verified == true`, true},
+		{"boolean usable as condition", `P.attr.verified && !context.mfa`, true},
+		{"string is not coerced to number", `P.attr.zip == "01234"`, true},
+		{"nested map value is coerced", `P.attr.limits.max >= 10`, true},
+		{"list elements are coerced", `2 in P.attr.levels`, true},
+		{"context number compares with int literal", `context.retries < 5`, true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := engine.EvaluateExpression(tt.expr, ctx)
+			if err != nil {
+				t.Fatalf("EvaluateExpression() error = %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("EvaluateExpression(%s) = %v, want %v", tt.expr, got, tt.want)
+			}
+		})
+	}
+
+	// Normalization must not mutate the caller's maps
+	if _, ok := attrs["age"].(float64); !ok {
+		t.Errorf("Expected caller attributes to be left untouched, got %T", attrs["age"])
+	}
+}
+
+func TestNormalizeValue_JSONNumber(t *testing.T) {
+	decoder := json.NewDecoder(strings.NewReader(`{"count": 7, "ratio": 0.25}`))
+	decoder.UseNumber()
+
+	var m map[string]interface{}
+	if err := decoder.Decode(&m); err != nil {
+		t.Fatalf("Failed to decode JSON: %v", err)
+	}
+
+	normalized, changed := normalizeMap(m)
+	if !changed {
+		t.Fatal("Expected json.Number values to be normalized")
+	}
+	if v, ok := normalized["count"].(int64); !ok || v != 7 {
+		t.Errorf("Expected count to be int64(7), got %T(%v)", normalized["count"], normalized["count"])
+	}
+	if v, ok := normalized["ratio"].(float64); !ok || v != 0.25 {
+		t.Errorf("Expected ratio to be float64(0.25), got %T(%v)", normalized["ratio"], normalized["ratio"])
+	}
+}
@@ -42,6 +42,8 @@ func NewEngine() (*Engine, error) {
 			decls.NewVar("request", decls.NewMapType(decls.String, decls.Dyn)),
 			decls.NewVar("context", decls.NewMapType(decls.String, decls.Dyn)),
 		),
+		// Allow <, <=, >, >= between int, uint and double (see coerce.go)
+		cel.CrossTypeNumericComparisons(true),
 		// Custom authorization functions
 		cel.Declarations(
 			// hasRole(principal, role) -> bool
@@ -123,15 +125,21 @@ func (e *Engine) Compile(expr string) (cel.Program, error) {
 	return prog, nil
 }
 
-// Evaluate evaluates a compiled program with the given context
+// Evaluate evaluates a compiled program with the given context.
+// Variable values are normalized first; see coerce.go for the type coercion rules.
 func (e *Engine) Evaluate(prog cel.Program, ctx *EvalContext) (bool, error) {
+	principal, _ := normalizeMap(ctx.Principal)
+	resource, _ := normalizeMap(ctx.Resource)
+	request, _ := normalizeMap(ctx.Request)
+	reqContext, _ := normalizeMap(ctx.Context)
+
 	vars := map[string]interface{}{
-		"principal": ctx.Principal,
-		"P":         ctx.Principal,
-		"resource":  ctx.Resource,
-		"R":         ctx.Resource,
-		"request":   ctx.Request,
-		"context":   ctx.Context,
+		"principal": principal,
+		"P":         principal,
+		"resource":  resource,
+		"R":         resource,
+		"request":   request,
+		"context":   reqContext,
 	}
 
 	result, _, err := prog.Eval(vars)
@@ -0,0 +1,120 @@
+package engine
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/authz-engine/go-core/internal/cache"
+	"github.com/authz-engine/go-core/pkg/types"
+)
+
+// ErrAttributesNotFound is returned by an AttributeProvider when it has no record of the principal.
+// The engine treats it as "no additional attributes" rather than a failure.
+var ErrAttributesNotFound = errors.New("principal attributes not found")
+
+// AttributeProvider supplies principal attributes from an external source (e.g. a directory)
+// so callers don't have to pass every attribute referenced by P.attr.* conditions
+type AttributeProvider interface {
+	// GetAttributes returns the attributes for a principal ID
+	GetAttributes(ctx context.Context, principalID string) (map[string]interface{}, error)
+}
+
+// AttributeProviderFunc adapts a plain function to the AttributeProvider interface
+type AttributeProviderFunc func(ctx context.Context, principalID string) (map[string]interface{}, error)
+
+// GetAttributes implements AttributeProvider
+func (f AttributeProviderFunc) GetAttributes(ctx context.Context, principalID string) (map[string]interface{}, error) {
+	return f(ctx, principalID)
+}
+
+// attributeResolver wraps an AttributeProvider with a short-lived cache
+type attributeResolver struct {
+	provider AttributeProvider
+	cache    cache.Cache
+	required bool
+}
+
+func newAttributeResolver(provider AttributeProvider, ttl time.Duration, required bool) *attributeResolver {
+	if ttl == 0 {
+		ttl = 30 * time.Second
+	}
+	return &attributeResolver{
+		provider: provider,
+		cache:    cache.NewLRU(10000, ttl),
+		required: required,
+	}
+}
+
+// enrich merges provider attributes into the principal.
+// Attributes supplied on the request take precedence over provider values.
+func (r *attributeResolver) enrich(ctx context.Context, principal *types.Principal) error {
+	if principal == nil || principal.ID == "" {
+		return nil
+	}
+
+	external, err := r.lookup(ctx, principal.ID)
+	if err != nil {
+		if r.required {
+			return fmt.Errorf("failed to resolve attributes for principal %q: %w", principal.ID, err)
+		}
+		// Graceful degradation: continue with request-supplied attributes only
+		return nil
+	}
+	if len(external) == 0 {
+		return nil
+	}
+
+	merged := make(map[string]interface{}, len(external)+len(principal.Attributes))
+	for k, v := range external {
+		merged[k] = v
+	}
+	for k, v := range principal.Attributes {
+		merged[k] = v
+	}
+	principal.Attributes = merged
+
+	return nil
+}
+
+func (r *attributeResolver) lookup(ctx context.Context, principalID string) (map[string]interface{}, error) {
+	if cached, ok := r.cache.Get(principalID); ok {
+		return cached.(map[string]interface{}), nil
+	}
+
+	attrs, err := r.provider.GetAttributes(ctx, principalID)
+	if errors.Is(err, ErrAttributesNotFound) {
+		attrs, err = map[string]interface{}{}, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	// Cache a copy so a provider reusing or mutating its maps can't change cached attributes
+	cached := make(map[string]interface{}, len(attrs))
+	for k, v := range attrs {
+		cached[k] = v
+	}
+
+	r.cache.Set(principalID, cached)
+	return cached, nil
+}
+
+// attributesFingerprint hashes an attribute map for use in decision cache keys.
+// JSON encoding sorts map keys, so equal maps produce equal fingerprints.
+func attributesFingerprint(attrs map[string]interface{}) (string, bool) {
+	if len(attrs) == 0 {
+		return "", true
+	}
+
+	data, err := json.Marshal(attrs)
+	if err != nil {
+		return "", false
+	}
+	hash := sha256.Sum256(data)
+	return hex.EncodeToString(hash[:16]), true
+}
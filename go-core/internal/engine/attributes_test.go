@@ -0,0 +1,203 @@
+package engine
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// mockAttributeProvider serves attributes from a fixed directory and counts lookups
+type mockAttributeProvider struct {
+	directory map[string]map[string]interface{}
+	err       error
+	calls     int
+}
+
+func (m *mockAttributeProvider) GetAttributes(_ context.Context, principalID string) (map[string]interface{}, error) {
+	m.calls++
+	if m.err != nil {
+		return nil, m.err
+	}
+	attrs, ok := m.directory[principalID]
+	if !ok {
+		return nil, ErrAttributesNotFound
+	}
+	return attrs, nil
+}
+
+func newDepartmentStore() policy.Store {
+	store := policy.NewMemoryStore()
+	store.Add(&types.Policy{
+		Name:         "engineering-docs",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{
+				Name:      "engineering-read",
+				Actions:   []string{"read"},
+				Effect:    types.EffectAllow,
+				Condition: `P.attr.department == "engineering"`,
+			},
+		},
+	})
+	return store
+}
+
+func newDepartmentRequest(principalID string) *types.CheckRequest {
+	return &types.CheckRequest{
+		RequestID: "attr-1",
+		Principal: &types.Principal{ID: principalID, Roles: []string{"user"}},
+		Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+		Actions:   []string{"read"},
+	}
+}
+
+func TestEngine_AttributeProvider_EnrichesPrincipal(t *testing.T) {
+	provider := &mockAttributeProvider{
+		directory: map[string]map[string]interface{}{
+			"alice": {"department": "engineering"},
+		},
+	}
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.AttributeProvider = provider
+
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+
+	resp, err := eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+
+	// Second check is served from the attribute cache
+	_, err = eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.Equal(t, 1, provider.calls)
+}
+
+func TestEngine_AttributeProvider_RequestAttributesTakePrecedence(t *testing.T) {
+	provider := &mockAttributeProvider{
+		directory: map[string]map[string]interface{}{
+			"alice": {"department": "engineering"},
+		},
+	}
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.AttributeProvider = provider
+
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+
+	req := newDepartmentRequest("alice")
+	req.Principal.Attributes = map[string]interface{}{"department": "sales"}
+
+	resp, err := eng.Check(context.Background(), req)
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+}
+
+func TestEngine_AttributeProvider_DecisionCacheEnabled(t *testing.T) {
+	provider := &mockAttributeProvider{
+		directory: map[string]map[string]interface{}{
+			"alice": {"department": "engineering"},
+		},
+	}
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = true
+	cfg.AttributeProvider = provider
+
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+
+	req := newDepartmentRequest("alice")
+	resp, err := eng.Check(context.Background(), req)
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+
+	// Enrichment works on a copy, the caller's principal is unchanged
+	assert.Nil(t, req.Principal.Attributes)
+
+	// Same request and resolved attributes: served from the decision cache
+	resp, err = eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.True(t, resp.Metadata.CacheHit)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+
+	// Same request, different resolved attributes: the cached decision must not be reused
+	req = newDepartmentRequest("alice")
+	req.Principal.Attributes = map[string]interface{}{"department": "sales"}
+	resp, err = eng.Check(context.Background(), req)
+	require.NoError(t, err)
+	assert.False(t, resp.Metadata.CacheHit)
+	assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+	assert.Equal(t, map[string]interface{}{"department": "sales"}, req.Principal.Attributes)
+}
+
+func TestEngine_AttributeProvider_CachedAttributesAreCopied(t *testing.T) {
+	attrs := map[string]interface{}{"department": "engineering"}
+	provider := &mockAttributeProvider{
+		directory: map[string]map[string]interface{}{"alice": attrs},
+	}
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.AttributeProvider = provider
+
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+
+	resp, err := eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+
+	// The provider mutating its own map doesn't reach the attribute cache
+	attrs["department"] = "sales"
+	resp, err = eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+	assert.Equal(t, 1, provider.calls)
+}
+
+func TestEngine_AttributeProvider_MissingPrincipal(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.AttributeProvider = &mockAttributeProvider{directory: map[string]map[string]interface{}{}}
+	cfg.AttributesRequired = true
+
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+
+	// Not found is not an error, the principal simply has no extra attributes
+	resp, err := eng.Check(context.Background(), newDepartmentRequest("bob"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+}
+
+func TestEngine_AttributeProvider_Failure(t *testing.T) {
+	provider := &mockAttributeProvider{err: errors.New("directory unavailable")}
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.AttributeProvider = provider
+
+	// Best effort: check proceeds with request attributes only
+	eng, err := New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+	resp, err := eng.Check(context.Background(), newDepartmentRequest("alice"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+
+	// Required: provider failure surfaces as an error
+	cfg.AttributesRequired = true
+	eng, err = New(cfg, newDepartmentStore())
+	require.NoError(t, err)
+	_, err = eng.Check(context.Background(), newDepartmentRequest("alice"))
+	assert.Error(t, err)
+}
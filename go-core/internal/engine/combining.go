@@ -0,0 +1,88 @@
+package engine
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/authz-engine/go-core/pkg/types"
+)
+
+// CombiningAlgorithm selects how the effects of multiple matching policies combine
+type CombiningAlgorithm string
+
+const (
+	// CombiningPriority evaluates tiers in priority order (principal-specific > role-based > resource).
+	// The first tier with a match decides, and deny overrides allow within that tier. This is the default.
+	CombiningPriority CombiningAlgorithm = ""
+	// CombiningFirstApplicable returns the first matching rule in priority order, whether allow or deny
+	CombiningFirstApplicable CombiningAlgorithm = "first-applicable"
+	// CombiningDenyOverrides returns deny if any matching policy denies, regardless of priority
+	CombiningDenyOverrides CombiningAlgorithm = "deny-overrides"
+	// CombiningPermitOverrides returns allow if any matching policy allows, regardless of priority
+	CombiningPermitOverrides CombiningAlgorithm = "permit-overrides"
+)
+
+// validate checks that the algorithm is one of the supported values
+func (a CombiningAlgorithm) validate() error {
+	switch a {
+	case CombiningPriority, CombiningFirstApplicable, CombiningDenyOverrides, CombiningPermitOverrides:
+		return nil
+	default:
+		return fmt.Errorf("unknown combining algorithm: %q", string(a))
+	}
+}
+
+// combineTiers evaluates all tiers for an action using a non-default combining algorithm.
+// Tiers are walked in priority order so FirstApplicable and the fallback results follow priority.
+// Exact action matches never hide a deny; they only decide which allow is reported.
+func (e *Engine) combineTiers(ctx context.Context, req *types.CheckRequest, tiers [][]*types.Policy, action string) types.ActionResult {
+	var firstAllow, firstExactAllow, firstDeny *types.ActionResult
+
+	for _, tier := range tiers {
+		for _, pol := range tier {
+			result, exact := e.evaluatePolicy(ctx, req, pol, action)
+			if !result.Matched {
+				continue
+			}
+
+			switch e.config.CombiningAlgorithm {
+			case CombiningFirstApplicable:
+				return result
+			case CombiningDenyOverrides:
+				if result.Effect == types.EffectDeny {
+					return result
+				}
+			case CombiningPermitOverrides:
+				if result.Effect == types.EffectAllow && exact {
+					return result
+				}
+			}
+
+			if result.Effect == types.EffectAllow && firstAllow == nil {
+				firstAllow = &result
+			}
+			if result.Effect == types.EffectAllow && exact && firstExactAllow == nil {
+				firstExactAllow = &result
+			}
+			if result.Effect == types.EffectDeny && firstDeny == nil {
+				firstDeny = &result
+			}
+		}
+	}
+
+	// No overriding effect was found; return whichever effect did match
+	if firstExactAllow != nil {
+		return *firstExactAllow
+	}
+	if firstAllow != nil {
+		return *firstAllow
+	}
+	if firstDeny != nil {
+		return *firstDeny
+	}
+
+	return types.ActionResult{
+		Effect:  e.config.DefaultEffect,
+		Matched: false,
+	}
+}
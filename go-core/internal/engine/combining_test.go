@@ -0,0 +1,113 @@
+package engine
+
+import (
+	"context"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newCombiningStore creates a principal policy for alice (highest priority) and a
+// resource policy for documents that both match a "read" by alice
+func newCombiningStore(t *testing.T, principalEffect, resourceEffect types.Effect) policy.Store {
+	t.Helper()
+
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:            "alice-documents",
+		ResourceKind:    "document",
+		PrincipalPolicy: true,
+		Principal:       &types.PrincipalSelector{ID: "alice"},
+		Resources:       []*types.ResourceSelector{{Kind: "document"}},
+		Rules: []*types.Rule{
+			{Name: "alice-read", Actions: []string{"read"}, Effect: principalEffect},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "documents",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "user-read", Actions: []string{"read"}, Effect: resourceEffect, Roles: []string{"user"}},
+		},
+	}))
+	return store
+}
+
+func checkWithAlgorithm(t *testing.T, store policy.Store, algorithm CombiningAlgorithm) types.ActionResult {
+	t.Helper()
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.CombiningAlgorithm = algorithm
+
+	eng, err := New(cfg, store)
+	require.NoError(t, err)
+
+	resp, err := eng.Check(context.Background(), &types.CheckRequest{
+		RequestID: "combining",
+		Principal: &types.Principal{ID: "alice", Roles: []string{"user"}},
+		Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+		Actions:   []string{"read"},
+	})
+	require.NoError(t, err)
+	return resp.Results["read"]
+}
+
+func TestEngine_CombiningAlgorithm_AllowBeforeDeny(t *testing.T) {
+	store := newCombiningStore(t, types.EffectAllow, types.EffectDeny)
+
+	tests := []struct {
+		algorithm CombiningAlgorithm
+		effect    types.Effect
+		policy    string
+	}{
+		{CombiningPriority, types.EffectAllow, "alice-documents"},
+		{CombiningFirstApplicable, types.EffectAllow, "alice-documents"},
+		{CombiningDenyOverrides, types.EffectDeny, "documents"},
+		{CombiningPermitOverrides, types.EffectAllow, "alice-documents"},
+	}
+
+	for _, tt := range tests {
+		t.Run(string(tt.algorithm), func(t *testing.T) {
+			result := checkWithAlgorithm(t, store, tt.algorithm)
+			assert.True(t, result.Matched)
+			assert.Equal(t, tt.effect, result.Effect)
+			assert.Equal(t, tt.policy, result.Policy)
+		})
+	}
+}
+
+func TestEngine_CombiningAlgorithm_DenyBeforeAllow(t *testing.T) {
+	store := newCombiningStore(t, types.EffectDeny, types.EffectAllow)
+
+	tests := []struct {
+		algorithm CombiningAlgorithm
+		effect    types.Effect
+		policy    string
+	}{
+		{CombiningPriority, types.EffectDeny, "alice-documents"},
+		{CombiningFirstApplicable, types.EffectDeny, "alice-documents"},
+		{CombiningDenyOverrides, types.EffectDeny, "alice-documents"},
+		{CombiningPermitOverrides, types.EffectAllow, "documents"},
+	}
+
+	for _, tt := range tests {
+		t.Run(string(tt.algorithm), func(t *testing.T) {
+			result := checkWithAlgorithm(t, store, tt.algorithm)
+			assert.True(t, result.Matched)
+			assert.Equal(t, tt.effect, result.Effect)
+			assert.Equal(t, tt.policy, result.Policy)
+		})
+	}
+}
+
+func TestEngine_CombiningAlgorithm_Unknown(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CombiningAlgorithm = "most-recent"
+
+	_, err := New(cfg, policy.NewMemoryStore())
+	assert.Error(t, err)
+}
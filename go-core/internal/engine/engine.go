@@ -38,6 +38,9 @@ type Engine struct {
 	// Phase 4.4: Prometheus metrics (optional, nil if not enabled)
 	metrics metrics.Metrics
 
+	// Principal attribute enrichment (optional, nil if no provider configured)
+	attributeResolver *attributeResolver
+
 	config Config
 }
 
@@ -53,6 +56,8 @@ type Config struct {
 	ParallelWorkers int
 	// DefaultEffect is the effect when no policy matches
 	DefaultEffect types.Effect
+	// CombiningAlgorithm selects how multiple matching policies combine (default: tiered priority)
+	CombiningAlgorithm CombiningAlgorithm
 
 	// Phase 5: Vector similarity configuration (optional)
 	// VectorSimilarityEnabled enables vector-based policy similarity search
@@ -64,6 +69,21 @@ type Config struct {
 
 	// Phase 4.4: Prometheus metrics (optional, nil defaults to NoOpMetrics)
 	Metrics metrics.Metrics
+
+	// AttributeProvider enriches principal attributes from an external source (optional).
+	// Cached decisions are keyed by the resolved attributes as well as the request.
+	AttributeProvider AttributeProvider
+	// AttributeCacheTTL is how long provider results are cached per principal (default 30s)
+	AttributeCacheTTL time.Duration
+	// AttributesRequired fails the check when the provider errors; otherwise the
+	// engine continues with the attributes supplied on the request
+	AttributesRequired bool
+
+	// TenantIsolation restricts every check to policies owned by CheckRequest.TenantID.
+	// Scope inheritance stops at the tenant root and untenanted global policies never apply.
+	// The gRPC and REST servers take the tenant from the authenticated token's tenant_id claim,
+	// so unauthenticated requests are always denied when this is enabled.
+	TenantIsolation bool
 }
 
 // DefaultConfig returns a default engine configuration
@@ -79,6 +99,10 @@ func DefaultConfig() Config {
 
 // New creates a new decision engine
 func New(cfg Config, store policy.Store) (*Engine, error) {
+	if err := cfg.CombiningAlgorithm.validate(); err != nil {
+		return nil, err
+	}
+
 	celEngine, err := cel.NewEngine()
 	if err != nil {
 		return nil, err
@@ -115,6 +139,10 @@ func New(cfg Config, store policy.Store) (*Engine, error) {
 		config:               cfg,
 	}
 
+	if cfg.AttributeProvider != nil {
+		engine.attributeResolver = newAttributeResolver(cfg.AttributeProvider, cfg.AttributeCacheTTL, cfg.AttributesRequired)
+	}
+
 	// Phase 5: Initialize vector similarity if enabled
 	if cfg.VectorSimilarityEnabled && cfg.VectorStore != nil {
 		engine.vectorStore = cfg.VectorStore
@@ -157,10 +185,26 @@ func (e *Engine) Check(ctx context.Context, req *types.CheckRequest) (*types.Che
 
 	start := time.Now()
 
+	// Enrich a copy of the principal from the external provider before the cache lookup,
+	// so the decision cache key reflects the resolved attributes. The caller's request is untouched.
+	if e.attributeResolver != nil && req.Principal != nil {
+		principal := *req.Principal
+		if err := e.attributeResolver.enrich(ctx, &principal); err != nil {
+			return nil, err
+		}
+		enriched := *req
+		enriched.Principal = &principal
+		req = &enriched
+	}
+
 	// Check cache first
 	cacheHit := false
+	var cacheKey string
+	useCache := false
 	if e.cache != nil {
-		cacheKey := req.CacheKey()
+		cacheKey, useCache = e.decisionCacheKey(req)
+	}
+	if useCache {
 		if cached, ok := e.cache.Get(cacheKey); ok {
 			resp := cached.(*types.CheckResponse)
 			resp.Metadata.CacheHit = true
@@ -235,7 +279,7 @@ func (e *Engine) Check(ctx context.Context, req *types.CheckRequest) (*types.Che
 
 	// Phase 4.4: Record metrics for cache miss and authorization check
 	duration := time.Since(start)
-	if !cacheHit && e.cache != nil {
+	if !cacheHit && useCache {
 		e.metrics.RecordCacheMiss()
 	}
 	// Record check metrics (get first result from map)
@@ -249,13 +293,30 @@ func (e *Engine) Check(ctx context.Context, req *types.CheckRequest) (*types.Che
 	}
 
 	// Cache result
-	if e.cache != nil {
-		e.cache.Set(req.CacheKey(), response)
+	if useCache {
+		e.cache.Set(cacheKey, response)
 	}
 
 	return response, nil
 }
 
+// decisionCacheKey returns the decision cache key for a request.
+// With an attribute provider the key also covers the resolved principal attributes, since
+// provider values can change a decision without changing the request; it reports false
+// when those attributes can't be fingerprinted and the decision must not be cached.
+func (e *Engine) decisionCacheKey(req *types.CheckRequest) (string, bool) {
+	key := req.CacheKey()
+	if e.attributeResolver == nil {
+		return key, true
+	}
+
+	fingerprint, ok := attributesFingerprint(req.Principal.Attributes)
+	if !ok {
+		return "", false
+	}
+	return key + ":" + fingerprint, true
+}
+
 // CheckBatch evaluates multiple authorization requests
 func (e *Engine) CheckBatch(ctx context.Context, requests []*types.CheckRequest) ([]*types.CheckResponse, error) {
 	responses := make([]*types.CheckResponse, len(requests))
@@ -300,6 +361,9 @@ func (e *Engine) evaluateParallel(ctx context.Context, req *types.CheckRequest,
 		}
 	}
 
+	// Tracks whether each action's current allow came from an exact rule
+	exactAllow := make(map[string]bool)
+
 	for _, action := range req.Actions {
 		for _, pol := range policies {
 			wg.Add(1)
@@ -309,13 +373,21 @@ func (e *Engine) evaluateParallel(ctx context.Context, req *types.CheckRequest,
 			e.workerPool.Submit(func() {
 				defer wg.Done()
 
-				result := e.evaluatePolicy(ctx, req, pol, action)
+				result, exact := e.evaluatePolicy(ctx, req, pol, action)
 
 				mu.Lock()
 				existing := results[action]
-				// Deny takes precedence, or update if we found a match
-				if result.Matched && (result.Effect == types.EffectDeny || !existing.Matched) {
+				switch {
+				case !result.Matched || (existing.Matched && existing.Effect == types.EffectDeny):
+					// No match, or a deny already decided this action
+				case result.Effect == types.EffectDeny || !existing.Matched:
+					// Deny takes precedence, or update if we found a match
 					results[action] = result
+					exactAllow[action] = exact
+				case exact && !exactAllow[action]:
+					// An exact allow is reported over a wildcard allow
+					results[action] = result
+					exactAllow[action] = true
 				}
 				mu.Unlock()
 			})
@@ -326,8 +398,11 @@ func (e *Engine) evaluateParallel(ctx context.Context, req *types.CheckRequest,
 	return results
 }
 
-// evaluatePolicy evaluates a single policy for an action
-func (e *Engine) evaluatePolicy(ctx context.Context, req *types.CheckRequest, pol *types.Policy, action string) types.ActionResult {
+// evaluatePolicy evaluates a single policy for an action.
+// Rules are checked in order and the first match decides. The second return value reports
+// whether that rule names the action exactly (or via the bare "*") rather than through a
+// family pattern such as "document:*"; combining uses it to prefer exact allows.
+func (e *Engine) evaluatePolicy(ctx context.Context, req *types.CheckRequest, pol *types.Policy, action string) (types.ActionResult, bool) {
 	// For principal policies, check if resource matches any resource selector
 	if pol.PrincipalPolicy {
 		resourceMatched := false
@@ -342,7 +417,7 @@ func (e *Engine) evaluatePolicy(ctx context.Context, req *types.CheckRequest, po
 			return types.ActionResult{
 				Effect:  e.config.DefaultEffect,
 				Matched: false,
-			}
+			}, false
 		}
 	}
 
@@ -378,14 +453,14 @@ func (e *Engine) evaluatePolicy(ctx context.Context, req *types.CheckRequest, po
 			Policy:  pol.Name,
 			Rule:    rule.Name,
 			Matched: true,
-		}
+		}, rule.MatchesActionExactly(action)
 	}
 
 	// No matching rule found
 	return types.ActionResult{
 		Effect:  e.config.DefaultEffect,
 		Matched: false,
-	}
+	}, false
 }
 
 // computeEffectiveScope determines which scope to use for policy resolution
@@ -529,6 +604,9 @@ func (e *Engine) findPoliciesWithPrincipalSeparate(req *types.CheckRequest) ([]*
 
 	// 1. Principal-specific policies (highest priority)
 	principalPolicies := e.store.FindPoliciesByPrincipal(req.Principal.ID, req.Resource.Kind)
+	if e.config.TenantIsolation {
+		principalPolicies = filterTenantPolicies(principalPolicies, req.TenantID)
+	}
 	if len(principalPolicies) > 0 {
 		resolution.PrincipalPoliciesMatched = true
 		resolution.EvaluationOrder = append(resolution.EvaluationOrder, "principal-specific")
@@ -538,6 +616,9 @@ func (e *Engine) findPoliciesWithPrincipalSeparate(req *types.CheckRequest) ([]*
 	var rolePolicies []*types.Policy
 	if len(req.Principal.Roles) > 0 {
 		rolePolicies = e.store.FindPoliciesByRoles(req.Principal.Roles, req.Resource.Kind)
+		if e.config.TenantIsolation {
+			rolePolicies = filterTenantPolicies(rolePolicies, req.TenantID)
+		}
 		if len(rolePolicies) > 0 {
 			resolution.PrincipalPoliciesMatched = true
 			resolution.EvaluationOrder = append(resolution.EvaluationOrder, "role-based-principal")
@@ -546,7 +627,13 @@ func (e *Engine) findPoliciesWithPrincipalSeparate(req *types.CheckRequest) ([]*
 
 	// 3. Resource policies (Phase 2 scope resolution)
 	effectiveScope := e.computeEffectiveScope(req)
-	resourcePolicies, scopeResult := e.findPoliciesWithScope(effectiveScope, req.Resource.Kind, req.Actions)
+	var resourcePolicies []*types.Policy
+	var scopeResult *types.ScopeResolutionResult
+	if e.config.TenantIsolation {
+		resourcePolicies, scopeResult = e.findTenantPoliciesWithScope(req.TenantID, effectiveScope, req.Resource.Kind, req.Actions)
+	} else {
+		resourcePolicies, scopeResult = e.findPoliciesWithScope(effectiveScope, req.Resource.Kind, req.Actions)
+	}
 	if len(resourcePolicies) > 0 {
 		resolution.ResourcePoliciesMatched = true
 		resolution.EvaluationOrder = append(resolution.EvaluationOrder, "resource-scoped")
@@ -559,6 +646,7 @@ func (e *Engine) findPoliciesWithPrincipalSeparate(req *types.CheckRequest) ([]*
 // evaluateWithPriority evaluates policies with priority-based resolution
 // Priority: principal-specific > role-based > resource policies
 // Within each tier, deny-overrides applies
+// Other combining algorithms are delegated to combineTiers
 func (e *Engine) evaluateWithPriority(ctx context.Context, req *types.CheckRequest, principalPolicies, rolePolicies, resourcePolicies []*types.Policy) map[string]types.ActionResult {
 	results := make(map[string]types.ActionResult)
 
@@ -570,6 +658,14 @@ func (e *Engine) evaluateWithPriority(ctx context.Context, req *types.CheckReque
 		}
 	}
 
+	if e.config.CombiningAlgorithm != CombiningPriority {
+		tiers := [][]*types.Policy{principalPolicies, rolePolicies, resourcePolicies}
+		for _, action := range req.Actions {
+			results[action] = e.combineTiers(ctx, req, tiers, action)
+		}
+		return results
+	}
+
 	// Evaluate each action
 	for _, action := range req.Actions {
 		// Try principal-specific policies first (highest priority)
@@ -602,12 +698,15 @@ func (e *Engine) evaluateWithPriority(ctx context.Context, req *types.CheckReque
 	return results
 }
 
-// evaluatePolicyTier evaluates a tier of policies for an action with deny-overrides
+// evaluatePolicyTier evaluates a tier of policies for an action with deny-overrides.
+// Any matching deny wins, whether exact or wildcard; among allows, one from a rule
+// naming the action exactly is preferred over a wildcard allow.
 func (e *Engine) evaluatePolicyTier(ctx context.Context, req *types.CheckRequest, policies []*types.Policy, action string) types.ActionResult {
 	var allowResult *types.ActionResult
+	allowExact := false
 
 	for _, pol := range policies {
-		result := e.evaluatePolicy(ctx, req, pol, action)
+		result, exact := e.evaluatePolicy(ctx, req, pol, action)
 
 		if !result.Matched {
 			continue
@@ -618,9 +717,10 @@ func (e *Engine) evaluatePolicyTier(ctx context.Context, req *types.CheckRequest
 			return result
 		}
 
-		// Keep first allow result
-		if allowResult == nil {
+		// Keep first allow result, upgrading to the first exact allow
+		if allowResult == nil || (exact && !allowExact) {
 			allowResult = &result
+			allowExact = exact
 		}
 	}
 
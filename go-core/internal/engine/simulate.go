@@ -0,0 +1,116 @@
+package engine
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+)
+
+// SimulationResult compares the decision for one request action under the
+// current policy set and a candidate policy set
+type SimulationResult struct {
+	RequestID string             `json:"requestId"`
+	Action    string             `json:"action"`
+	Current   types.ActionResult `json:"current"`
+	Candidate types.ActionResult `json:"candidate"`
+	Changed   bool               `json:"changed"` // Effect flips between current and candidate
+}
+
+// Simulate evaluates requests against both the current policies and a candidate
+// policy set without committing the change. The candidate set replaces all
+// current policies (derived roles are kept), so to preview adding a policy pass
+// append(store.GetAll(), newPolicy).
+// Results are returned per request action, in request order.
+func (e *Engine) Simulate(ctx context.Context, candidate []*types.Policy, requests []*types.CheckRequest) ([]SimulationResult, error) {
+	candidateStore := policy.NewMemoryStore()
+	for _, pol := range candidate {
+		if err := candidateStore.Add(pol); err != nil {
+			return nil, fmt.Errorf("invalid candidate policy %q: %w", pol.Name, err)
+		}
+	}
+	for _, dr := range e.store.GetDerivedRoles() {
+		if err := candidateStore.AddDerivedRole(dr); err != nil {
+			return nil, fmt.Errorf("failed to copy derived role %q: %w", dr.Name, err)
+		}
+	}
+
+	current, err := e.shadow(e.store)
+	if err != nil {
+		return nil, err
+	}
+	defer current.workerPool.Stop()
+
+	next, err := e.shadow(candidateStore)
+	if err != nil {
+		return nil, err
+	}
+	defer next.workerPool.Stop()
+
+	results := make([]SimulationResult, 0, len(requests))
+	for _, req := range requests {
+		currentResp, err := current.Check(ctx, cloneRequest(req))
+		if err != nil {
+			return nil, fmt.Errorf("current evaluation failed for request %q: %w", req.RequestID, err)
+		}
+		candidateResp, err := next.Check(ctx, cloneRequest(req))
+		if err != nil {
+			return nil, fmt.Errorf("candidate evaluation failed for request %q: %w", req.RequestID, err)
+		}
+
+		for _, action := range req.Actions {
+			before := currentResp.Results[action]
+			after := candidateResp.Results[action]
+			results = append(results, SimulationResult{
+				RequestID: req.RequestID,
+				Action:    action,
+				Current:   before,
+				Candidate: after,
+				Changed:   before.Effect != after.Effect,
+			})
+		}
+	}
+
+	return results, nil
+}
+
+// shadow creates an uncached engine over store with this engine's evaluation settings
+func (e *Engine) shadow(store policy.Store) (*Engine, error) {
+	cfg := Config{
+		CacheEnabled:       false,
+		ParallelWorkers:    e.config.ParallelWorkers,
+		DefaultEffect:      e.config.DefaultEffect,
+		CombiningAlgorithm: e.config.CombiningAlgorithm,
+		AttributeProvider:  e.config.AttributeProvider,
+		AttributeCacheTTL:  e.config.AttributeCacheTTL,
+		AttributesRequired: e.config.AttributesRequired,
+		TenantIsolation:    e.config.TenantIsolation,
+	}
+	if cfg.ParallelWorkers == 0 {
+		cfg.ParallelWorkers = 1
+	}
+
+	shadow, err := New(cfg, store)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create simulation engine: %w", err)
+	}
+	return shadow, nil
+}
+
+// cloneRequest copies the parts of a request that Check mutates (roles, attributes)
+func cloneRequest(req *types.CheckRequest) *types.CheckRequest {
+	clone := *req
+	if req.Principal != nil {
+		principal := *req.Principal
+		principal.Roles = append([]string(nil), req.Principal.Roles...)
+		if req.Principal.Attributes != nil {
+			principal.Attributes = make(map[string]interface{}, len(req.Principal.Attributes))
+			for k, v := range req.Principal.Attributes {
+				principal.Attributes[k] = v
+			}
+		}
+		clone.Principal = &principal
+	}
+	return &clone
+}
@@ -0,0 +1,82 @@
+package engine
+
+import (
+	"context"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestEngine_Simulate_ReportsFlippedDecision(t *testing.T) {
+	adminPolicy := &types.Policy{
+		Name:         "admin-docs",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "admin-all", Actions: []string{"*"}, Effect: types.EffectAllow, Roles: []string{"admin"}},
+		},
+	}
+
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(adminPolicy))
+
+	eng, err := New(DefaultConfig(), store)
+	require.NoError(t, err)
+
+	editorPolicy := &types.Policy{
+		Name:         "editor-docs",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "editor-write", Actions: []string{"write"}, Effect: types.EffectAllow, Roles: []string{"editor"}},
+		},
+	}
+
+	requests := []*types.CheckRequest{
+		{
+			RequestID: "admin",
+			Principal: &types.Principal{ID: "alice", Roles: []string{"admin"}},
+			Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+			Actions:   []string{"write"},
+		},
+		{
+			RequestID: "editor",
+			Principal: &types.Principal{ID: "bob", Roles: []string{"editor"}},
+			Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+			Actions:   []string{"read", "write"},
+		},
+	}
+
+	results, err := eng.Simulate(context.Background(), []*types.Policy{adminPolicy, editorPolicy}, requests)
+	require.NoError(t, err)
+	require.Len(t, results, 3)
+
+	var changed []SimulationResult
+	for _, r := range results {
+		if r.Changed {
+			changed = append(changed, r)
+		}
+	}
+
+	require.Len(t, changed, 1)
+	assert.Equal(t, "editor", changed[0].RequestID)
+	assert.Equal(t, "write", changed[0].Action)
+	assert.Equal(t, types.EffectDeny, changed[0].Current.Effect)
+	assert.Equal(t, types.EffectAllow, changed[0].Candidate.Effect)
+	assert.Equal(t, "editor-docs", changed[0].Candidate.Policy)
+
+	// The change was not committed
+	assert.Equal(t, 1, store.Count())
+	resp, err := eng.Check(context.Background(), requests[1])
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["write"].Effect)
+}
+
+func TestEngine_Simulate_InvalidCandidate(t *testing.T) {
+	eng, err := New(DefaultConfig(), policy.NewMemoryStore())
+	require.NoError(t, err)
+
+	_, err = eng.Simulate(context.Background(), []*types.Policy{{ResourceKind: "document"}}, nil)
+	assert.Error(t, err)
+}
@@ -0,0 +1,74 @@
+package engine
+
+import (
+	"strings"
+
+	"github.com/authz-engine/go-core/pkg/types"
+)
+
+// findTenantPoliciesWithScope resolves resource policies when tenant isolation is enabled.
+// The chain is tenant-qualified ("t.acme.eng" -> "t.acme" -> "t") and ends at the tenant
+// root, so only the request tenant's policies are considered and there is no global fallback.
+func (e *Engine) findTenantPoliciesWithScope(tenantID, requestScope, resourceKind string, actions []string) ([]*types.Policy, *types.ScopeResolutionResult) {
+	scopeResult := &types.ScopeResolutionResult{
+		InheritanceChain:    []string{},
+		ScopedPolicyMatched: false,
+	}
+
+	chain, err := e.scopeResolver.BuildTenantScopeChain(tenantID, requestScope)
+	if err != nil {
+		// Missing or invalid tenant/scope, fail closed (deny)
+		scopeResult.MatchedScope = "(invalid)"
+		scopeResult.InheritanceChain = []string{requestScope}
+		return []*types.Policy{}, scopeResult
+	}
+
+	scopeResult.InheritanceChain = chain
+
+	for _, qualified := range chain {
+		// Policy scopes are stored tenant-local; the tenant root holds the tenant's unscoped policies
+		localScope := ""
+		if qualified != tenantID {
+			localScope = strings.TrimPrefix(qualified, tenantID+".")
+		}
+
+		var candidates []*types.Policy
+		if localScope == "" {
+			candidates = e.store.FindPolicies(resourceKind, actions)
+		} else {
+			candidates = e.store.FindPoliciesForScope(localScope, resourceKind, actions)
+		}
+
+		tenantPolicies := make([]*types.Policy, 0, len(candidates))
+		for _, p := range candidates {
+			// Only include this tenant's resource policies (not principal policies)
+			if !p.PrincipalPolicy && p.TenantID == tenantID && p.Scope == localScope {
+				tenantPolicies = append(tenantPolicies, p)
+			}
+		}
+		if len(tenantPolicies) > 0 {
+			scopeResult.MatchedScope = qualified
+			scopeResult.ScopedPolicyMatched = localScope != ""
+			return tenantPolicies, scopeResult
+		}
+	}
+
+	scopeResult.MatchedScope = "(none)"
+	return []*types.Policy{}, scopeResult
+}
+
+// filterTenantPolicies keeps only policies owned by the tenant.
+// A request without a tenant matches nothing.
+func filterTenantPolicies(policies []*types.Policy, tenantID string) []*types.Policy {
+	if tenantID == "" {
+		return nil
+	}
+
+	filtered := make([]*types.Policy, 0, len(policies))
+	for _, p := range policies {
+		if p.TenantID == tenantID {
+			filtered = append(filtered, p)
+		}
+	}
+	return filtered
+}
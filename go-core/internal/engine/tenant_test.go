@@ -0,0 +1,197 @@
+package engine
+
+import (
+	"context"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newTenantStore creates policies for two tenants that use the same scope string,
+// plus an untenanted global policy that must never apply under isolation
+func newTenantStore(t *testing.T) policy.Store {
+	t.Helper()
+
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "tenant-a-eng-docs",
+		TenantID:     "tenant-a",
+		ResourceKind: "document",
+		Scope:        "acme.engineering",
+		Rules: []*types.Rule{
+			{Name: "a-read", Actions: []string{"read"}, Effect: types.EffectAllow},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "tenant-b-root-docs",
+		TenantID:     "tenant-b",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "b-write", Actions: []string{"write"}, Effect: types.EffectAllow},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:            "tenant-a-alice",
+		TenantID:        "tenant-a",
+		ResourceKind:    "document",
+		PrincipalPolicy: true,
+		Principal:       &types.PrincipalSelector{ID: "alice"},
+		Resources:       []*types.ResourceSelector{{Kind: "document"}},
+		Rules: []*types.Rule{
+			{Name: "alice-delete", Actions: []string{"delete"}, Effect: types.EffectAllow},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "global-docs",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "global-read", Actions: []string{"read", "write", "delete"}, Effect: types.EffectAllow},
+		},
+	}))
+	return store
+}
+
+func tenantRequest(tenantID string) *types.CheckRequest {
+	return &types.CheckRequest{
+		RequestID: "tenant-" + tenantID,
+		TenantID:  tenantID,
+		Principal: &types.Principal{ID: "alice", Roles: []string{"user"}},
+		Resource:  &types.Resource{Kind: "document", ID: "doc-1", Scope: "acme.engineering"},
+		Actions:   []string{"read", "write", "delete"},
+	}
+}
+
+func TestEngine_TenantIsolation_SameScopeDifferentTenants(t *testing.T) {
+	cfg := DefaultConfig() // decision cache enabled: tenants must not share cache entries
+	cfg.TenantIsolation = true
+
+	eng, err := New(cfg, newTenantStore(t))
+	require.NoError(t, err)
+
+	respA, err := eng.Check(context.Background(), tenantRequest("tenant-a"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, respA.Results["read"].Effect)
+	assert.Equal(t, "tenant-a-eng-docs", respA.Results["read"].Policy)
+	assert.Equal(t, types.EffectAllow, respA.Results["delete"].Effect)
+	// tenant-b's root policy and the untenanted global policy never apply to tenant-a
+	assert.Equal(t, types.EffectDeny, respA.Results["write"].Effect)
+	assert.Equal(t, []string{"tenant-a.acme.engineering", "tenant-a.acme", "tenant-a"}, respA.Metadata.ScopeResolution.InheritanceChain)
+
+	respB, err := eng.Check(context.Background(), tenantRequest("tenant-b"))
+	require.NoError(t, err)
+	assert.False(t, respB.Metadata.CacheHit)
+	// Same scope string, but tenant-a's scoped and principal policies don't match tenant-b
+	assert.Equal(t, types.EffectDeny, respB.Results["read"].Effect)
+	assert.Equal(t, types.EffectDeny, respB.Results["delete"].Effect)
+	// tenant-b inherits its own root policy
+	assert.Equal(t, types.EffectAllow, respB.Results["write"].Effect)
+	assert.Equal(t, "tenant-b-root-docs", respB.Results["write"].Policy)
+}
+
+func TestEngine_TenantIsolation_MissingTenantFailsClosed(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.TenantIsolation = true
+
+	eng, err := New(cfg, newTenantStore(t))
+	require.NoError(t, err)
+
+	resp, err := eng.Check(context.Background(), tenantRequest(""))
+	require.NoError(t, err)
+	for _, action := range []string{"read", "write", "delete"} {
+		assert.Equal(t, types.EffectDeny, resp.Results[action].Effect, action)
+		assert.False(t, resp.Results[action].Matched, action)
+	}
+}
+
+func TestEngine_TenantIsolation_Disabled(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+
+	eng, err := New(cfg, newTenantStore(t))
+	require.NoError(t, err)
+
+	// Without isolation, the tenant field is ignored and the global policy applies as before
+	resp, err := eng.Check(context.Background(), tenantRequest("tenant-b"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+}
+
+func TestEngine_TenantIsolation_SharedPolicyName(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.TenantIsolation = true
+
+	docsPolicy := func(tenantID, action string) *types.Policy {
+		return &types.Policy{
+			Name:         "docs",
+			TenantID:     tenantID,
+			ResourceKind: "document",
+			Rules: []*types.Rule{
+				{Name: tenantID + "-" + action, Actions: []string{action}, Effect: types.EffectAllow},
+			},
+		}
+	}
+
+	store := policy.NewMemoryStore()
+	eng, err := New(cfg, store)
+	require.NoError(t, err)
+
+	require.NoError(t, store.Add(docsPolicy("tenant-a", "read")))
+
+	// tenant-b can't take over tenant-a's policy name
+	assert.Error(t, store.Add(docsPolicy("tenant-b", "write")))
+	got, err := store.Get("docs")
+	require.NoError(t, err)
+	assert.Equal(t, "tenant-a", got.TenantID)
+	assert.Len(t, store.GetAll(), 1)
+
+	resp, err := eng.Check(context.Background(), tenantRequest("tenant-a"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+	resp, err = eng.Check(context.Background(), tenantRequest("tenant-b"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["write"].Effect)
+
+	// Once tenant-a removes it, the name is free and only tenant-b's policy applies
+	require.NoError(t, store.Remove("docs"))
+	require.NoError(t, store.Add(docsPolicy("tenant-b", "write")))
+
+	resp, err = eng.Check(context.Background(), tenantRequest("tenant-a"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+	resp, err = eng.Check(context.Background(), tenantRequest("tenant-b"))
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["write"].Effect)
+	assert.Equal(t, "docs", resp.Results["write"].Policy)
+}
+
+func TestEngine_TenantIsolation_MaxDepthScope(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+	cfg.TenantIsolation = true
+
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "tenant-a-root",
+		TenantID:     "tenant-a",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "a-read", Actions: []string{"read"}, Effect: types.EffectAllow},
+		},
+	}))
+
+	eng, err := New(cfg, store)
+	require.NoError(t, err)
+
+	// A scope at the resolver's MaxDepth stays valid once the tenant segment is prepended
+	req := tenantRequest("tenant-a")
+	req.Resource.Scope = "a.b.c.d.e.f.g.h.i.j"
+	resp, err := eng.Check(context.Background(), req)
+	require.NoError(t, err)
+	assert.Equal(t, types.EffectAllow, resp.Results["read"].Effect)
+	assert.Equal(t, "tenant-a", resp.Metadata.ScopeResolution.MatchedScope)
+}
@@ -0,0 +1,160 @@
+package engine
+
+import (
+	"context"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func checkActions(t *testing.T, cfg *Config, store policy.Store, actions ...string) map[string]types.ActionResult {
+	t.Helper()
+
+	eng, err := New(*cfg, store)
+	require.NoError(t, err)
+
+	resp, err := eng.Check(context.Background(), &types.CheckRequest{
+		RequestID: "wildcard-actions",
+		Principal: &types.Principal{ID: "alice", Roles: []string{"editor"}},
+		Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+		Actions:   actions,
+	})
+	require.NoError(t, err)
+	return resp.Results
+}
+
+func TestEngine_WildcardAction_MatchesFamily(t *testing.T) {
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "document-editors",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "edit-documents", Actions: []string{"document:*"}, Effect: types.EffectAllow, Roles: []string{"editor"}},
+		},
+	}))
+
+	cfg := DefaultConfig()
+	cfg.CacheEnabled = false
+
+	results := checkActions(t, &cfg, store, "document:read", "document:write", "document:share:external", "folder:read")
+	assert.Equal(t, types.EffectAllow, results["document:read"].Effect)
+	assert.Equal(t, types.EffectAllow, results["document:write"].Effect)
+	// A single "*" matches exactly one segment
+	assert.Equal(t, types.EffectDeny, results["document:share:external"].Effect)
+	assert.False(t, results["folder:read"].Matched)
+}
+
+func TestEngine_WildcardAction_ExactAllowPreferred(t *testing.T) {
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "document-editors",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "edit-documents", Actions: []string{"document:*"}, Effect: types.EffectAllow, Roles: []string{"editor"}},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "document-readers",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "allow-read", Actions: []string{"document:read"}, Effect: types.EffectAllow, Roles: []string{"editor"}},
+		},
+	}))
+
+	for _, algorithm := range []CombiningAlgorithm{CombiningPriority, CombiningDenyOverrides, CombiningPermitOverrides} {
+		t.Run(string(algorithm), func(t *testing.T) {
+			cfg := DefaultConfig()
+			cfg.CacheEnabled = false
+			cfg.CombiningAlgorithm = algorithm
+
+			results := checkActions(t, &cfg, store, "document:read", "document:write")
+
+			// Both allow read; the exact rule is the one reported
+			assert.Equal(t, types.EffectAllow, results["document:read"].Effect)
+			assert.Equal(t, "allow-read", results["document:read"].Rule)
+
+			// Only the wildcard applies to write
+			assert.Equal(t, types.EffectAllow, results["document:write"].Effect)
+			assert.Equal(t, "edit-documents", results["document:write"].Rule)
+		})
+	}
+}
+
+func TestEngine_WildcardAction_DenyNotHiddenByExactAllow(t *testing.T) {
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "document-lockdown",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "deny-document-actions", Actions: []string{"document:*"}, Effect: types.EffectDeny},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:         "document-readers",
+		ResourceKind: "document",
+		Rules: []*types.Rule{
+			{Name: "allow-read", Actions: []string{"document:read"}, Effect: types.EffectAllow, Roles: []string{"editor"}},
+		},
+	}))
+
+	for _, algorithm := range []CombiningAlgorithm{CombiningPriority, CombiningDenyOverrides} {
+		t.Run(string(algorithm), func(t *testing.T) {
+			cfg := DefaultConfig()
+			cfg.CacheEnabled = false
+			cfg.CombiningAlgorithm = algorithm
+
+			results := checkActions(t, &cfg, store, "document:read")
+			assert.Equal(t, types.EffectDeny, results["document:read"].Effect)
+			assert.Equal(t, "deny-document-actions", results["document:read"].Rule)
+		})
+	}
+}
+
+// Regression: a same-tier deny on bare "*" keeps winning over an exact allow
+func TestEngine_WildcardAction_BareStarDenyWinsInTier(t *testing.T) {
+	store := policy.NewMemoryStore()
+	require.NoError(t, store.Add(&types.Policy{
+		Name:            "blocklist",
+		ResourceKind:    "document",
+		PrincipalPolicy: true,
+		Principal:       &types.PrincipalSelector{Roles: []string{"blocked"}},
+		Resources:       []*types.ResourceSelector{{Kind: "document"}},
+		Rules: []*types.Rule{
+			{Name: "deny-all", Actions: []string{"*"}, Effect: types.EffectDeny},
+		},
+	}))
+	require.NoError(t, store.Add(&types.Policy{
+		Name:            "editors-read",
+		ResourceKind:    "document",
+		PrincipalPolicy: true,
+		Principal:       &types.PrincipalSelector{Roles: []string{"editor"}},
+		Resources:       []*types.ResourceSelector{{Kind: "document"}},
+		Rules: []*types.Rule{
+			{Name: "allow-read", Actions: []string{"read"}, Effect: types.EffectAllow},
+		},
+	}))
+
+	for _, algorithm := range []CombiningAlgorithm{CombiningPriority, CombiningDenyOverrides} {
+		t.Run(string(algorithm), func(t *testing.T) {
+			cfg := DefaultConfig()
+			cfg.CacheEnabled = false
+			cfg.CombiningAlgorithm = algorithm
+
+			eng, err := New(cfg, store)
+			require.NoError(t, err)
+
+			resp, err := eng.Check(context.Background(), &types.CheckRequest{
+				RequestID: "blocked-editor",
+				Principal: &types.Principal{ID: "mallory", Roles: []string{"editor", "blocked"}},
+				Resource:  &types.Resource{Kind: "document", ID: "doc-1"},
+				Actions:   []string{"read"},
+			})
+			require.NoError(t, err)
+			assert.Equal(t, types.EffectDeny, resp.Results["read"].Effect)
+			assert.Equal(t, "blocklist", resp.Results["read"].Policy)
+		})
+	}
+}
@@ -88,6 +88,17 @@ func (s *MemoryStore) Add(policy *types.Policy) error {
 		}
 	}
 
+	// Policies are keyed by name, so a name can't be shared across tenants.
+	// Re-adding a name within the same tenant replaces the previous policy.
+	if existing, ok := s.policies[policy.Name]; ok {
+		if existing.TenantID != policy.TenantID {
+			return fmt.Errorf("policy name %q is already used by another tenant", policy.Name)
+		}
+		s.index.Remove(existing)
+		s.scopeIndex.Remove(existing)
+		s.principalIndex.Remove(existing)
+	}
+
 	s.policies[policy.Name] = policy
 	s.index.Add(policy)
 	s.scopeIndex.Add(policy)
@@ -141,6 +152,11 @@ func (s *MemoryStore) Reload() error {
 	return nil
 }
 
+// samePolicy reports whether two policies share an identity (tenant and name)
+func samePolicy(a, b *types.Policy) bool {
+	return a.TenantID == b.TenantID && a.Name == b.Name
+}
+
 // Index provides fast policy lookup by resource kind
 type Index struct {
 	byResource map[string][]*types.Policy
@@ -169,7 +185,7 @@ func (i *Index) Remove(policy *types.Policy) {
 
 	policies := i.byResource[policy.ResourceKind]
 	for j, p := range policies {
-		if p.Name == policy.Name {
+		if samePolicy(p, policy) {
 			i.byResource[policy.ResourceKind] = append(policies[:j], policies[j+1:]...)
 			break
 		}
@@ -244,7 +260,7 @@ func (i *ScopeIndex) Remove(policy *types.Policy) {
 	if kindMap, ok := i.byScope[policy.Scope]; ok {
 		policies := kindMap[policy.ResourceKind]
 		for j, p := range policies {
-			if p.Name == policy.Name {
+			if samePolicy(p, policy) {
 				kindMap[policy.ResourceKind] = append(policies[:j], policies[j+1:]...)
 				break
 			}
@@ -93,7 +93,7 @@ func (i *PrincipalIndex) Remove(policy *types.Policy) {
 			for _, res := range policy.Resources {
 				policies := kindMap[res.Kind]
 				for j, p := range policies {
-					if p.Name == policy.Name {
+					if samePolicy(p, policy) {
 						kindMap[res.Kind] = append(policies[:j], policies[j+1:]...)
 						break
 					}
@@ -118,7 +118,7 @@ func (i *PrincipalIndex) Remove(policy *types.Policy) {
 				for _, res := range policy.Resources {
 					policies := kindMap[res.Kind]
 					for j, p := range policies {
-						if p.Name == policy.Name {
+						if samePolicy(p, policy) {
 							kindMap[res.Kind] = append(policies[:j], policies[j+1:]...)
 							break
 						}
@@ -3,6 +3,7 @@ package policy
 import (
 	"fmt"
 	"regexp"
+	"strings"
 
 	"github.com/authz-engine/go-core/pkg/types"
 	"github.com/google/cel-go/cel"
@@ -213,7 +214,7 @@ func (v *Validator) checkForConflicts(policy *types.Policy) error {
 func hasOverlappingActions(actions1, actions2 []string) bool {
 	for _, a1 := range actions1 {
 		for _, a2 := range actions2 {
-			if a1 == "*" || a2 == "*" || a1 == a2 {
+			if actionPatternsOverlap(a1, a2) {
 				return true
 			}
 		}
@@ -221,6 +222,40 @@ func hasOverlappingActions(actions1, actions2 []string) bool {
 	return false
 }
 
+// actionPatternsOverlap checks if some concrete action matches both patterns.
+// Patterns are compared segment by segment, with "*" and "**" acting as wildcards on either side.
+func actionPatternsOverlap(a, b string) bool {
+	if a == "*" || b == "*" || a == b {
+		return true
+	}
+	return actionSegmentsOverlap(strings.Split(a, ":"), strings.Split(b, ":"))
+}
+
+func actionSegmentsOverlap(a, b []string) bool {
+	if len(a) == 0 && len(b) == 0 {
+		return true
+	}
+
+	// "**" absorbs zero or more segments from the other side
+	if len(a) > 0 && a[0] == "**" {
+		if actionSegmentsOverlap(a[1:], b) {
+			return true
+		}
+		return len(b) > 0 && actionSegmentsOverlap(a, b[1:])
+	}
+	if len(b) > 0 && b[0] == "**" {
+		return actionSegmentsOverlap(b, a)
+	}
+
+	if len(a) == 0 || len(b) == 0 {
+		return false
+	}
+	if a[0] == "*" || b[0] == "*" || a[0] == b[0] {
+		return actionSegmentsOverlap(a[1:], b[1:])
+	}
+	return false
+}
+
 // isValidIdentifier checks if a string is a valid identifier
 func isValidIdentifier(s string) bool {
 	// Allow alphanumeric, hyphens, and underscores
@@ -236,9 +271,16 @@ func isValidAction(action string) bool {
 	if action == "*" {
 		return true
 	}
+	// Wildcards must span a whole segment ("document:*", "document:**"); validate the rest as a name
+	segments := strings.Split(action, ":")
+	for i, seg := range segments {
+		if seg == "*" || seg == "**" {
+			segments[i] = "x"
+		}
+	}
 	// Fixed regex: hyphen must be at end of character class or escaped
 	pattern := `^[a-zA-Z_][a-zA-Z0-9_:\-]*$`
-	matched, err := regexp.MatchString(pattern, action)
+	matched, err := regexp.MatchString(pattern, strings.Join(segments, ":"))
 	return err == nil && matched
 }
 
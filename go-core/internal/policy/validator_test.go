@@ -286,6 +286,10 @@ func TestValidator_InvalidActions(t *testing.T) {
 		{"read_all", "read_all", true},
 		{"namespace:read", "namespace:read", true},
 		{"*", "*", true},
+		{"namespace:*", "namespace:*", true},
+		{"namespace:**", "namespace:**", true},
+		{"*:read", "*:read", true},
+		{"partial wildcard", "namespace:re*", false},
 		{"123invalid", "123invalid", false},
 		{"-invalid", "-invalid", false},
 		{"with space", "with space", false},
@@ -392,3 +396,33 @@ func TestValidator_MultipleValidPolicies(t *testing.T) {
 		}
 	}
 }
+
+func TestHasOverlappingActions(t *testing.T) {
+	tests := []struct {
+		name     string
+		actions1 []string
+		actions2 []string
+		want     bool
+	}{
+		{"same action", []string{"read"}, []string{"read"}, true},
+		{"different actions", []string{"read"}, []string{"write"}, false},
+		{"bare wildcard", []string{"*"}, []string{"document:read"}, true},
+		{"family covers action", []string{"document:*"}, []string{"document:read"}, true},
+		{"family vs other family", []string{"document:*"}, []string{"folder:*"}, false},
+		{"wildcards on different segments", []string{"document:*"}, []string{"*:read"}, true},
+		{"segment count differs", []string{"document:*"}, []string{"*:read:all"}, false},
+		{"double star vs deeper pattern", []string{"document:**"}, []string{"*:share:external"}, true},
+		{"double star vs other prefix", []string{"document:**"}, []string{"folder:*:read"}, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := hasOverlappingActions(tt.actions1, tt.actions2); got != tt.want {
+				t.Errorf("hasOverlappingActions(%v, %v) = %v, want %v", tt.actions1, tt.actions2, got, tt.want)
+			}
+			if got := hasOverlappingActions(tt.actions2, tt.actions1); got != tt.want {
+				t.Errorf("hasOverlappingActions(%v, %v) = %v, want %v", tt.actions2, tt.actions1, got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,53 @@
+package scope
+
+import (
+	"fmt"
+)
+
+// QualifyScope prepends the tenant ID to a scope so identical scope strings
+// from different tenants never collide
+// Example: ("tenant-a", "acme.corp") -> "tenant-a.acme.corp"
+// MaxDepth applies to the tenant-local scope; the tenant segment doesn't count against it.
+func (r *Resolver) QualifyScope(tenantID, scope string) (string, error) {
+	if err := r.validateTenantID(tenantID); err != nil {
+		return "", err
+	}
+	if err := r.ValidateScope(scope); err != nil {
+		return "", err
+	}
+	if scope == "" {
+		return tenantID, nil
+	}
+	return tenantID + "." + scope, nil
+}
+
+// BuildTenantScopeChain builds the inheritance chain for a tenant-local scope.
+// Every entry is tenant-qualified and the chain ends at the tenant root,
+// so resolution can never fall through to another tenant's policies.
+// Example: ("tenant-a", "acme.corp") -> ["tenant-a.acme.corp", "tenant-a.acme", "tenant-a"]
+func (r *Resolver) BuildTenantScopeChain(tenantID, scope string) ([]string, error) {
+	if err := r.validateTenantID(tenantID); err != nil {
+		return nil, err
+	}
+
+	local, err := r.BuildScopeChain(scope)
+	if err != nil {
+		return nil, err
+	}
+
+	chain := make([]string, 0, len(local)+1)
+	for _, s := range local {
+		chain = append(chain, tenantID+"."+s)
+	}
+	return append(chain, tenantID), nil
+}
+
+func (r *Resolver) validateTenantID(tenantID string) error {
+	if tenantID == "" {
+		return fmt.Errorf("tenant ID is required")
+	}
+	if !r.config.AllowedCharsRegex.MatchString(tenantID) {
+		return fmt.Errorf("invalid tenant ID: %s (allowed: alphanumeric, underscore, hyphen)", tenantID)
+	}
+	return nil
+}
@@ -0,0 +1,82 @@
+package scope
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+)
+
+// TestQualifyScope tests tenant prefixing of scopes
+func TestQualifyScope(t *testing.T) {
+	resolver := NewResolver(DefaultConfig())
+
+	tests := []struct {
+		name     string
+		tenant   string
+		scope    string
+		expected string
+		wantErr  bool
+	}{
+		{name: "scoped", tenant: "tenant-a", scope: "acme.corp", expected: "tenant-a.acme.corp"},
+		{name: "tenant root", tenant: "tenant-a", scope: "", expected: "tenant-a"},
+		{name: "missing tenant", tenant: "", scope: "acme", wantErr: true},
+		{name: "tenant with dot", tenant: "tenant.a", scope: "acme", wantErr: true},
+		{name: "tenant wildcard", tenant: "*", scope: "acme", wantErr: true},
+		{name: "invalid scope", tenant: "tenant-a", scope: "acme..corp", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := resolver.QualifyScope(tt.tenant, tt.scope)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("QualifyScope() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.expected {
+				t.Errorf("QualifyScope() = %q, want %q", got, tt.expected)
+			}
+		})
+	}
+}
+
+// TestBuildTenantScopeChain tests that chains stay within the tenant
+func TestBuildTenantScopeChain(t *testing.T) {
+	resolver := NewResolver(DefaultConfig())
+
+	chain, err := resolver.BuildTenantScopeChain("tenant-a", "acme.corp")
+	if err != nil {
+		t.Fatalf("BuildTenantScopeChain() error = %v", err)
+	}
+
+	expected := []string{"tenant-a.acme.corp", "tenant-a.acme", "tenant-a"}
+	if !reflect.DeepEqual(chain, expected) {
+		t.Errorf("BuildTenantScopeChain() = %v, want %v", chain, expected)
+	}
+
+	for _, s := range chain {
+		if s != "tenant-a" && !strings.HasPrefix(s, "tenant-a.") {
+			t.Errorf("chain entry %q escaped tenant", s)
+		}
+	}
+}
+
+// TestBuildTenantScopeChain_MaxDepthExcludesTenant tests that the tenant segment doesn't use up a depth level
+func TestBuildTenantScopeChain_MaxDepthExcludesTenant(t *testing.T) {
+	resolver := NewResolver(DefaultConfig())
+
+	scope := "a.b.c.d.e.f.g.h.i.j" // exactly MaxDepth segments
+	if _, err := resolver.BuildScopeChain(scope); err != nil {
+		t.Fatalf("BuildScopeChain() error = %v", err)
+	}
+
+	chain, err := resolver.BuildTenantScopeChain("tenant-a", scope)
+	if err != nil {
+		t.Fatalf("BuildTenantScopeChain() error = %v", err)
+	}
+	if len(chain) != 11 || chain[0] != "tenant-a."+scope || chain[10] != "tenant-a" {
+		t.Errorf("BuildTenantScopeChain() = %v", chain)
+	}
+
+	if _, err := resolver.BuildTenantScopeChain("tenant-a", scope+".k"); err == nil {
+		t.Error("BuildTenantScopeChain() should reject a local scope deeper than MaxDepth")
+	}
+}
@@ -199,6 +199,14 @@ func ClaimsFromContext(ctx context.Context) (*auth.Claims, bool) {
 	return claims, ok
 }
 
+// TenantFromContext returns the tenant ID of the authenticated caller, or "" when unauthenticated
+func TenantFromContext(ctx context.Context) string {
+	if claims, ok := ClaimsFromContext(ctx); ok && claims != nil {
+		return claims.TenantID
+	}
+	return ""
+}
+
 // authenticatedStream wraps a grpc.ServerStream with an authenticated context
 type authenticatedStream struct {
 	grpc.ServerStream
@@ -19,6 +19,7 @@ import (
 
 	"github.com/authz-engine/go-core/internal/engine"
 	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/internal/server/middleware"
 	"github.com/authz-engine/go-core/pkg/types"
 )
 
@@ -308,7 +309,7 @@ func (s *Server) IsPolicyWatcherRunning() bool {
 // Check implements the Check RPC method
 func (s *Server) Check(ctx interface{}, req *CheckRequest) (*CheckResponse, error) {
 	// Convert protobuf request to internal types
-	internalReq := protoToCheckRequest(req)
+	internalReq := protoToCheckRequest(ctx.(context.Context), req)
 
 	// Execute authorization check
 	resp, err := s.engine.Check(ctx.(context.Context), internalReq)
@@ -329,7 +330,7 @@ func (s *Server) CheckBatch(ctx interface{}, req *CheckBatchRequest) (*CheckBatc
 	// Convert protobuf requests to internal types
 	internalReqs := make([]*types.CheckRequest, len(req.Requests))
 	for i, r := range req.Requests {
-		internalReqs[i] = protoToCheckRequest(r)
+		internalReqs[i] = protoToCheckRequest(ctx.(context.Context), r)
 	}
 
 	// Execute batch authorization check
@@ -365,8 +366,9 @@ func (s *Server) CheckStream(stream AuthzService_CheckStreamServer) error {
 		}
 
 		// Convert and execute check
-		internalReq := protoToCheckRequest(req)
-		resp, err := s.engine.Check(stream.Context().(context.Context), internalReq)
+		streamCtx := stream.Context().(context.Context)
+		internalReq := protoToCheckRequest(streamCtx, req)
+		resp, err := s.engine.Check(streamCtx, internalReq)
 		if err != nil {
 			s.logger.Error("Stream authorization check failed",
 				zap.String("request_id", req.RequestId),
@@ -390,8 +392,9 @@ func (s *Server) CheckStream(stream AuthzService_CheckStreamServer) error {
 	}
 }
 
-// protoToCheckRequest converts a protobuf CheckRequest to internal types
-func protoToCheckRequest(req *CheckRequest) *types.CheckRequest {
+// protoToCheckRequest converts a protobuf CheckRequest to internal types.
+// The tenant comes from the authenticated caller, never from the request body.
+func protoToCheckRequest(ctx context.Context, req *CheckRequest) *types.CheckRequest {
 	principal := &types.Principal{
 		ID:         req.Principal.Id,
 		Roles:      req.Principal.Roles,
@@ -406,6 +409,7 @@ func protoToCheckRequest(req *CheckRequest) *types.CheckRequest {
 
 	return &types.CheckRequest{
 		RequestID: req.RequestId,
+		TenantID:  middleware.TenantFromContext(ctx),
 		Principal: principal,
 		Resource:  resource,
 		Actions:   req.Actions,
@@ -219,7 +219,11 @@ func TestProtoToCheckRequest(t *testing.T) {
 		Actions: []string{"read", "write"},
 	}
 
-	internal := protoToCheckRequest(protoReq)
+	internal := protoToCheckRequest(context.Background(), protoReq)
+
+	if internal.TenantID != "" {
+		t.Errorf("Expected no tenant for an unauthenticated request, got '%s'", internal.TenantID)
+	}
 
 	if internal.RequestID != "test-proto" {
 		t.Errorf("Expected request_id 'test-proto', got '%s'", internal.RequestID)
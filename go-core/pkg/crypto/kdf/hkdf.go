@@ -0,0 +1,33 @@
+// Package kdf provides key derivation helpers shared across the authorization engine
+package kdf
+
+import (
+	"crypto/hkdf"
+	"crypto/sha256"
+	"fmt"
+)
+
+// MaxOutputLength is the largest key HKDF-SHA256 can produce (255 * hash length)
+const MaxOutputLength = 255 * sha256.Size
+
+// HKDFExpand derives outLen bytes from input keying material using HKDF-SHA256 (RFC 5869).
+// It performs both the extract and expand steps; callers should use a distinct info
+// label per purpose (e.g. "authz/aead-key", "authz/session-key") so derived keys are independent.
+func HKDFExpand(ikm, salt, info []byte, outLen int) ([]byte, error) {
+	if len(ikm) == 0 {
+		return nil, fmt.Errorf("input keying material is required")
+	}
+	if outLen <= 0 {
+		return nil, fmt.Errorf("output length must be positive, got %d", outLen)
+	}
+	if outLen > MaxOutputLength {
+		return nil, fmt.Errorf("output length %d exceeds maximum %d", outLen, MaxOutputLength)
+	}
+
+	key, err := hkdf.Key(sha256.New, ikm, salt, string(info), outLen)
+	if err != nil {
+		return nil, fmt.Errorf("hkdf derivation failed: %w", err)
+	}
+
+	return key, nil
+}
@@ -0,0 +1,85 @@
+package kdf
+
+import (
+	"bytes"
+	"encoding/hex"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func mustHex(t *testing.T, s string) []byte {
+	t.Helper()
+	b, err := hex.DecodeString(s)
+	require.NoError(t, err)
+	return b
+}
+
+// TestHKDFExpand_RFC5869 checks known-answer vectors from RFC 5869 Appendix A
+func TestHKDFExpand_RFC5869(t *testing.T) {
+	tests := []struct {
+		name string
+		ikm  string
+		salt string
+		info string
+		l    int
+		okm  string
+	}{
+		{
+			name: "test case 1",
+			ikm:  "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+			salt: "000102030405060708090a0b0c",
+			info: "f0f1f2f3f4f5f6f7f8f9",
+			l:    42,
+			okm:  "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+		},
+		{
+			name: "test case 3 (empty salt and info)",
+			ikm:  "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+			salt: "",
+			info: "",
+			l:    42,
+			okm:  "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			okm, err := HKDFExpand(mustHex(t, tt.ikm), mustHex(t, tt.salt), mustHex(t, tt.info), tt.l)
+			require.NoError(t, err)
+			assert.Equal(t, tt.okm, hex.EncodeToString(okm))
+		})
+	}
+}
+
+// TestHKDFExpand_InfoLabelsAreIndependent ensures different purposes never share a key
+func TestHKDFExpand_InfoLabelsAreIndependent(t *testing.T) {
+	ikm := bytes.Repeat([]byte{0x42}, 32)
+	salt := []byte("authz-salt")
+
+	aeadKey, err := HKDFExpand(ikm, salt, []byte("authz/aead-key"), 32)
+	require.NoError(t, err)
+	sessionKey, err := HKDFExpand(ikm, salt, []byte("authz/session-key"), 32)
+	require.NoError(t, err)
+
+	assert.Len(t, aeadKey, 32)
+	assert.Len(t, sessionKey, 32)
+	assert.NotEqual(t, aeadKey, sessionKey)
+
+	// Same label is deterministic
+	again, err := HKDFExpand(ikm, salt, []byte("authz/aead-key"), 32)
+	require.NoError(t, err)
+	assert.Equal(t, aeadKey, again)
+}
+
+func TestHKDFExpand_InvalidInput(t *testing.T) {
+	_, err := HKDFExpand(nil, nil, nil, 32)
+	assert.Error(t, err)
+
+	_, err = HKDFExpand([]byte("ikm"), nil, nil, 0)
+	assert.Error(t, err)
+
+	_, err = HKDFExpand([]byte("ikm"), nil, nil, MaxOutputLength+1)
+	assert.Error(t, err)
+}
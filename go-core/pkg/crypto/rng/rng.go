@@ -0,0 +1,61 @@
+// Package rng is the single source of randomness for key generation.
+// Production code draws from the operating system CSPRNG via crypto/rand;
+// tests may swap in a seeded, deterministic source with UseSeededForTesting.
+package rng
+
+import (
+	"crypto/rand"
+	"fmt"
+	"io"
+	mrand "math/rand/v2"
+	"sync"
+	"testing"
+)
+
+var (
+	mu     sync.RWMutex
+	source io.Reader = rand.Reader
+)
+
+// Reader returns the active randomness source.
+// Unless overridden by a test, this is crypto/rand.Reader (getrandom(2) / /dev/urandom on Linux).
+func Reader() io.Reader {
+	mu.RLock()
+	defer mu.RUnlock()
+	return source
+}
+
+// SecureBytes returns n bytes from the active CSPRNG
+func SecureBytes(n int) ([]byte, error) {
+	if n < 0 {
+		return nil, fmt.Errorf("byte count must not be negative, got %d", n)
+	}
+
+	b := make([]byte, n)
+	if _, err := io.ReadFull(Reader(), b); err != nil {
+		return nil, fmt.Errorf("read secure random bytes: %w", err)
+	}
+	return b, nil
+}
+
+// UseSeededForTesting replaces the source with a ChaCha8 stream keyed by seed so
+// generated keys are reproducible. It returns a function restoring the previous source.
+// It panics when called outside a test binary, so a deterministic source can never
+// reach production key generation.
+func UseSeededForTesting(seed [32]byte) (restore func()) {
+	if !testing.Testing() {
+		panic("rng: UseSeededForTesting called outside of a test binary")
+	}
+
+	mu.Lock()
+	defer mu.Unlock()
+
+	prev := source
+	source = mrand.NewChaCha8(seed)
+
+	return func() {
+		mu.Lock()
+		defer mu.Unlock()
+		source = prev
+	}
+}
@@ -0,0 +1,49 @@
+package rng
+
+import (
+	"crypto/rand"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestReader_DefaultsToOSCSPRNG(t *testing.T) {
+	assert.Equal(t, rand.Reader, Reader())
+
+	a, err := SecureBytes(32)
+	require.NoError(t, err)
+	b, err := SecureBytes(32)
+	require.NoError(t, err)
+
+	assert.Len(t, a, 32)
+	assert.NotEqual(t, a, b)
+}
+
+func TestUseSeededForTesting_Deterministic(t *testing.T) {
+	seed := [32]byte{1, 2, 3}
+
+	restore := UseSeededForTesting(seed)
+	first, err := SecureBytes(64)
+	require.NoError(t, err)
+	restore()
+
+	restore = UseSeededForTesting(seed)
+	second, err := SecureBytes(64)
+	require.NoError(t, err)
+	restore()
+
+	assert.Equal(t, first, second)
+
+	// Restore puts the OS source back
+	assert.Equal(t, rand.Reader, Reader())
+}
+
+func TestSecureBytes_InvalidLength(t *testing.T) {
+	_, err := SecureBytes(-1)
+	assert.Error(t, err)
+
+	b, err := SecureBytes(0)
+	require.NoError(t, err)
+	assert.Empty(t, b)
+}
@@ -73,6 +73,7 @@ type CheckRequest struct {
 	Actions         []string               `json:"actions"`
 	Context         map[string]interface{} `json:"context"`
 	IncludeMetadata bool                   `json:"includeMetadata"`
+	TenantID        string                 `json:"tenantId,omitempty"` // Owning tenant when the engine runs with tenant isolation
 }
 
 // CacheKey generates a cache key for this request
@@ -85,7 +86,8 @@ func (r *CheckRequest) CacheKey() string {
 	copy(roles, r.Principal.Roles)
 	sort.Strings(roles)
 
-	key := fmt.Sprintf("%s:%s:%s:%s:%s:%s:%s",
+	key := fmt.Sprintf("%s:%s:%s:%s:%s:%s:%s:%s",
+		r.TenantID,
 		r.Principal.ID,
 		r.Principal.Scope,
 		strings.Join(roles, ","),
@@ -144,6 +146,7 @@ type Policy struct {
 	ResourceKind string  `json:"resourceKind" yaml:"resourceKind"`
 	Rules        []*Rule `json:"rules" yaml:"rules"`
 	Scope        string  `json:"scope,omitempty" yaml:"scope,omitempty"` // Hierarchical scope (e.g., "acme.corp.engineering")
+	TenantID     string  `json:"tenantId,omitempty" yaml:"tenantId,omitempty"` // Owning tenant; Scope is tenant-local when set
 
 	// Phase 3: Principal Policies
 	PrincipalPolicy bool                 `json:"principalPolicy,omitempty" yaml:"principalPolicy,omitempty"` // Marks this as a principal policy
@@ -161,16 +164,62 @@ type Rule struct {
 	DerivedRoles []string `json:"derivedRoles,omitempty" yaml:"derivedRoles,omitempty"`
 }
 
-// MatchesAction checks if the rule applies to an action
+// MatchesAction checks if the rule applies to an action.
+// Action patterns use scope wildcard semantics with ':' as the separator:
+// "document:*" matches one segment ("document:read"), "document:**" matches any
+// number of segments, and a bare "*" matches every action.
 func (r *Rule) MatchesAction(action string) bool {
 	for _, a := range r.Actions {
-		if a == "*" || a == action {
+		if MatchActionPattern(a, action) {
 			return true
 		}
 	}
 	return false
 }
 
+// MatchesActionExactly checks if the rule names the action literally or through the bare
+// "*" (which has always meant every action), rather than a family pattern like "document:*"
+func (r *Rule) MatchesActionExactly(action string) bool {
+	for _, a := range r.Actions {
+		if a == action || a == "*" {
+			return true
+		}
+	}
+	return false
+}
+
+// MatchActionPattern reports whether an action matches an action pattern
+func MatchActionPattern(pattern, action string) bool {
+	if pattern == "*" || pattern == action {
+		return true
+	}
+	if !strings.Contains(pattern, "*") {
+		return false
+	}
+	return matchActionSegments(strings.Split(pattern, ":"), strings.Split(action, ":"))
+}
+
+func matchActionSegments(pattern, action []string) bool {
+	if len(pattern) == 0 {
+		return len(action) == 0
+	}
+
+	switch pattern[0] {
+	case "**":
+		// Matches any number of segments, including none
+		for i := 0; i <= len(action); i++ {
+			if matchActionSegments(pattern[1:], action[i:]) {
+				return true
+			}
+		}
+		return false
+	case "*":
+		return len(action) > 0 && action[0] != "" && matchActionSegments(pattern[1:], action[1:])
+	default:
+		return len(action) > 0 && pattern[0] == action[0] && matchActionSegments(pattern[1:], action[1:])
+	}
+}
+
 // MatchesRole checks if any of the principal's roles match
 func (r *Rule) MatchesRole(principalRoles []string) bool {
 	if len(r.Roles) == 0 {
@@ -0,0 +1,35 @@
+package types
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestMatchActionPattern(t *testing.T) {
+	tests := []struct {
+		pattern string
+		action  string
+		want    bool
+	}{
+		{"read", "read", true},
+		{"read", "write", false},
+		{"*", "document:read", true},
+		{"document:*", "document:read", true},
+		{"document:*", "document:write", true},
+		{"document:*", "document", false},
+		{"document:*", "document:share:external", false},
+		{"document:**", "document:share:external", true},
+		{"document:**", "document", true},
+		{"*:read", "folder:read", true},
+		{"*:read", "folder:write", false},
+		{"document:*", "folder:read", false},
+		{"document:*", "document:", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.pattern+"/"+tt.action, func(t *testing.T) {
+			assert.Equal(t, tt.want, MatchActionPattern(tt.pattern, tt.action))
+		})
+	}
+}
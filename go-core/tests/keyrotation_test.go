@@ -175,6 +175,127 @@ func TestKeyRotationManager_MultipleRotations(t *testing.T) {
 	assert.Equal(t, keys[2].KID, activeKey.KID)
 }
 
+func TestKeyRotationManager_RewrapAll(t *testing.T) {
+	db := setupTestDB(t)
+	defer db.Close()
+
+	ctx := context.Background()
+	_, err := db.ExecContext(ctx, "DELETE FROM signing_keys")
+	require.NoError(t, err)
+
+	newAESEncryptor := func() *auth.AESKeyEncryptor {
+		key, err := auth.GenerateAESKey()
+		require.NoError(t, err)
+		encryptor, err := auth.NewAESKeyEncryptor(key)
+		require.NoError(t, err)
+		return encryptor
+	}
+	oldEncryptor := newAESEncryptor()
+	newEncryptor := newAESEncryptor()
+
+	krm := auth.NewKeyRotationManager(db, oldEncryptor)
+
+	keys := make([]*auth.SigningKey, 3)
+	for i := range keys {
+		key, err := krm.RotateKeys(ctx)
+		require.NoError(t, err)
+		keys[i] = key
+	}
+
+	// Rotate the master key and rewrap everything stored under the old one
+	progress, err := krm.RewrapAll(ctx, newEncryptor)
+	require.NoError(t, err)
+	assert.True(t, progress.Done)
+	assert.Equal(t, 3, progress.Total)
+	assert.Equal(t, 3, progress.Rewrapped)
+	assert.Empty(t, progress.Remaining)
+
+	// Every stored key now decrypts under the new master key only
+	for _, key := range keys {
+		var encrypted string
+		err := db.QueryRowContext(ctx,
+			"SELECT private_key_encrypted FROM signing_keys WHERE kid = $1", key.KID).Scan(&encrypted)
+		require.NoError(t, err)
+
+		_, err = newEncryptor.Decrypt(encrypted)
+		assert.NoError(t, err, key.KID)
+		_, err = oldEncryptor.Decrypt(encrypted)
+		assert.Error(t, err, key.KID)
+	}
+
+	// A manager that only knows the new master key can sign
+	activeKey, err := auth.NewKeyRotationManager(db, newEncryptor).GetActiveKey(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, keys[2].KID, activeKey.KID)
+	assert.NotNil(t, activeKey.GetPrivateKey())
+
+	// Running again is a no-op: progress is tracked per KID
+	progress, err = krm.RewrapAll(ctx, newEncryptor)
+	require.NoError(t, err)
+	assert.Equal(t, 0, progress.Rewrapped)
+	assert.Equal(t, 3, progress.AlreadyCurrent)
+}
+
+func TestKeyRotationManager_RewrapAll_ResumesAndReadsBothKeys(t *testing.T) {
+	db := setupTestDB(t)
+	defer db.Close()
+
+	ctx := context.Background()
+	_, err := db.ExecContext(ctx, "DELETE FROM signing_keys")
+	require.NoError(t, err)
+
+	oldKey, err := auth.GenerateAESKey()
+	require.NoError(t, err)
+	oldEncryptor, err := auth.NewAESKeyEncryptor(oldKey)
+	require.NoError(t, err)
+	newKey, err := auth.GenerateAESKey()
+	require.NoError(t, err)
+	newEncryptor, err := auth.NewAESKeyEncryptor(newKey)
+	require.NoError(t, err)
+
+	krm := auth.NewKeyRotationManager(db, oldEncryptor)
+	key1, err := krm.RotateKeys(ctx)
+	require.NoError(t, err)
+	key2, err := krm.RotateKeys(ctx)
+	require.NoError(t, err)
+
+	// Simulate a crash after key1 was rewrapped but before key2 was
+	var encrypted string
+	require.NoError(t, db.QueryRowContext(ctx,
+		"SELECT private_key_encrypted FROM signing_keys WHERE kid = $1", key1.KID).Scan(&encrypted))
+	plaintext, err := oldEncryptor.Decrypt(encrypted)
+	require.NoError(t, err)
+	rewrapped, err := newEncryptor.Encrypt(plaintext)
+	require.NoError(t, err)
+	_, err = db.ExecContext(ctx,
+		"UPDATE signing_keys SET private_key_encrypted = $1 WHERE kid = $2", rewrapped, key1.KID)
+	require.NoError(t, err)
+
+	// After the restart the manager still uses the old key; an interrupted attempt
+	// leaves it reading under both master keys
+	restarted := auth.NewKeyRotationManager(db, oldEncryptor)
+	cancelled, cancel := context.WithCancel(ctx)
+	cancel()
+	_, err = restarted.RewrapAll(cancelled, newEncryptor)
+	require.Error(t, err)
+
+	activeKey, err := restarted.GetActiveKey(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, key2.KID, activeKey.KID)
+	assert.NotNil(t, activeKey.GetPrivateKey())
+
+	// Resuming skips the key that was already rewrapped
+	progress, err := restarted.RewrapAll(ctx, newEncryptor)
+	require.NoError(t, err)
+	assert.True(t, progress.Done)
+	assert.Equal(t, 1, progress.AlreadyCurrent)
+	assert.Equal(t, 1, progress.Rewrapped)
+
+	activeKey, err = auth.NewKeyRotationManager(db, newEncryptor).GetActiveKey(ctx)
+	require.NoError(t, err)
+	assert.Equal(t, key2.KID, activeKey.KID)
+}
+
 func TestAESKeyEncryptor(t *testing.T) {
 	key, err := auth.GenerateAESKey()
 	require.NoError(t, err)
@@ -0,0 +1,207 @@
+package policy_test
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/authz-engine/go-core/internal/policy"
+	"github.com/authz-engine/go-core/pkg/types"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestPortable_RoundTrip(t *testing.T) {
+	source := policy.NewMemoryStore()
+
+	require.NoError(t, source.Add(&types.Policy{
+		APIVersion:   "v1",
+		Name:         "engineering-docs",
+		ResourceKind: "document",
+		Scope:        "acme.engineering",
+		Rules: []*types.Rule{
+			{
+				Name:      "read-own-department",
+				Actions:   []string{"read", "list"},
+				Effect:    types.EffectAllow,
+				Roles:     []string{"engineer"},
+				Condition: `P.attr.department == R.attr.department`,
+			},
+			{
+				Name:    "no-delete",
+				Actions: []string{"delete"},
+				Effect:  types.EffectDeny,
+			},
+		},
+	}))
+	require.NoError(t, source.Add(&types.Policy{
+		APIVersion:      "v1",
+		Name:            "alice-reports",
+		PrincipalPolicy: true,
+		Principal:       &types.PrincipalSelector{ID: "alice"},
+		Resources:       []*types.ResourceSelector{{Kind: "report", Scope: "acme.**"}},
+		Rules: []*types.Rule{
+			{Name: "alice-read", Actions: []string{"read"}, Effect: types.EffectAllow},
+		},
+	}))
+	require.NoError(t, source.AddDerivedRole(&types.DerivedRole{
+		Name:        "owner",
+		ParentRoles: []string{"user"},
+		Condition:   `R.attr.ownerId == P.id`,
+	}))
+
+	data, err := policy.ExportAll(source)
+	require.NoError(t, err)
+
+	target := policy.NewMemoryStore()
+	result, err := policy.ImportAll(target, data)
+	require.NoError(t, err)
+	assert.Equal(t, 3, result.Imported)
+
+	for _, want := range source.GetAll() {
+		got, err := target.Get(want.Name)
+		require.NoError(t, err)
+		assert.Equal(t, want, got)
+	}
+
+	dr, err := target.GetDerivedRole("owner")
+	require.NoError(t, err)
+	assert.Equal(t, `R.attr.ownerId == P.id`, dr.Condition)
+
+	// Re-exporting the imported set yields the same policies
+	again, err := policy.ExportAll(target)
+	require.NoError(t, err)
+	reimported := policy.NewMemoryStore()
+	_, err = policy.ImportAll(reimported, again)
+	require.NoError(t, err)
+	assert.Len(t, reimported.GetAll(), 2)
+}
+
+func TestPortable_ImportMalformed(t *testing.T) {
+	tests := []struct {
+		name    string
+		data    string
+		errPart string
+	}{
+		{
+			name:    "invalid JSON",
+			data:    `{"policies": [`,
+			errPart: "malformed policy set",
+		},
+		{
+			name:    "unknown field",
+			data:    `{"policies": [{"apiVersion": "v1", "name": "p", "resourceKind": "doc", "rulez": []}]}`,
+			errPart: `unknown field "rulez"`,
+		},
+		{
+			name:    "unsupported version",
+			data:    `{"policies": [], "metadata": {"version": "v9"}}`,
+			errPart: "unsupported policy set version",
+		},
+		{
+			name: "invalid rule effect",
+			data: `{"policies": [{"apiVersion": "v1", "name": "bad-policy", "resourceKind": "doc",
+				"rules": [{"name": "r", "actions": ["read"], "effect": "maybe"}]}]}`,
+			errPart: "policy bad-policy: rules[0].effect",
+		},
+		{
+			name: "invalid condition",
+			data: `{"policies": [{"apiVersion": "v1", "name": "bad-cel", "resourceKind": "doc",
+				"rules": [{"name": "r", "actions": ["read"], "effect": "allow", "condition": "P.attr.x =="}]}]}`,
+			errPart: "rules[0].condition",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			store := policy.NewMemoryStore()
+
+			_, err := policy.ImportAll(store, tt.data)
+			require.Error(t, err)
+			assert.Contains(t, err.Error(), tt.errPart)
+
+			// Nothing is written when the set is rejected
+			assert.Empty(t, store.GetAll())
+		})
+	}
+}
+
+func TestPortable_ImportWriteFailureLeavesStoreUnchanged(t *testing.T) {
+	// The second policy passes validation but is rejected by the store on write
+	data := `{"policies": [
+		{"apiVersion": "v1", "name": "good-policy", "resourceKind": "doc",
+			"rules": [{"name": "r", "actions": ["read"], "effect": "allow"}]},
+		{"apiVersion": "v1", "name": "bad-principal", "resourceKind": "doc", "principalPolicy": true,
+			"rules": [{"name": "r", "actions": ["read"], "effect": "allow"}]}
+	]}`
+
+	store := policy.NewMemoryStore()
+
+	_, err := policy.ImportAll(store, data)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "principal policy requires principal selector")
+
+	_, err = store.Get("good-policy")
+	assert.Error(t, err, "valid policy must not be written when the set is rejected")
+	assert.Empty(t, store.GetAll())
+}
+
+// rejectingStore fails writes for one policy name to exercise rollback
+type rejectingStore struct {
+	*policy.MemoryStore
+	reject string
+}
+
+func (s *rejectingStore) Add(p *types.Policy) error {
+	if p.Name == s.reject {
+		return fmt.Errorf("storage unavailable")
+	}
+	return s.MemoryStore.Add(p)
+}
+
+func TestPortable_ImportRollsBackOnTargetFailure(t *testing.T) {
+	store := &rejectingStore{MemoryStore: policy.NewMemoryStore(), reject: "second-policy"}
+	original := &types.Policy{
+		APIVersion:   "v1",
+		Name:         "first-policy",
+		ResourceKind: "doc",
+		Rules:        []*types.Rule{{Name: "original", Actions: []string{"read"}, Effect: types.EffectAllow}},
+	}
+	require.NoError(t, store.Add(original))
+
+	data := `{"policies": [
+		{"apiVersion": "v1", "name": "first-policy", "resourceKind": "doc",
+			"rules": [{"name": "replaced", "actions": ["read"], "effect": "deny"}]},
+		{"apiVersion": "v1", "name": "second-policy", "resourceKind": "doc",
+			"rules": [{"name": "r", "actions": ["read"], "effect": "allow"}]}
+	]}`
+
+	_, err := policy.ImportAll(store, data)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "policy second-policy")
+
+	got, err := store.Get("first-policy")
+	require.NoError(t, err)
+	assert.Equal(t, original, got)
+	assert.Len(t, store.GetAll(), 1)
+}
+
+func TestPortable_ImportReportsRollbackFailure(t *testing.T) {
+	// The store rejects every write of "shared-policy", including restoring the original
+	store := &rejectingStore{MemoryStore: policy.NewMemoryStore(), reject: "shared-policy"}
+	require.NoError(t, store.MemoryStore.Add(&types.Policy{
+		APIVersion:   "v1",
+		Name:         "shared-policy",
+		ResourceKind: "doc",
+		Rules:        []*types.Rule{{Name: "original", Actions: []string{"read"}, Effect: types.EffectAllow}},
+	}))
+
+	data := `{"policies": [
+		{"apiVersion": "v1", "name": "shared-policy", "resourceKind": "doc",
+			"rules": [{"name": "replaced", "actions": ["read"], "effect": "deny"}]}
+	]}`
+
+	_, err := policy.ImportAll(store, data)
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "policy shared-policy: failed to add policy")
+	assert.Contains(t, err.Error(), "rollback: policy shared-policy: failed to restore previous policy")
+}
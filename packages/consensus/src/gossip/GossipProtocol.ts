@@ -13,6 +13,7 @@ import {
 } from '../types.js';
 import type {
   GossipConfig,
+  GossipProposeOptions,
   GossipState,
   GossipUpdate,
   GossipMessage,
@@ -36,6 +37,9 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
 
   constructor(config: GossipConfig) {
     super();
+    if (config.defaultTtl !== undefined) {
+      assertValidTtl(config.defaultTtl, 'defaultTtl');
+    }
     this.config = config;
     this.nodes = new Map(config.nodes.map(n => [n.id, n]));
 
@@ -66,6 +70,7 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
       messagesReceived: 0,
       updatesProposed: 0,
       updatesPropagated: 0,
+      updatesDropped: 0,
       conflictsDetected: 0,
       conflictsResolved: 0,
       peersKnown: config.nodes.length,
@@ -79,7 +84,13 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
   // ConsensusProtocol Interface
   // ============================================================================
 
-  async propose(value: unknown): Promise<ConsensusResult> {
+  async propose(value: unknown, options: GossipProposeOptions = {}): Promise<ConsensusResult> {
+    // The origin sets the hop budget; the caller may override the configured default
+    if (options.ttl !== undefined) {
+      assertValidTtl(options.ttl, 'ttl');
+    }
+    const ttl = options.ttl ?? this.config.defaultTtl ?? this.config.maxRoundsToKeep;
+
     const proposalId = uuidv4();
     this.incrementClock();
     this.metrics.updatesProposed++;
@@ -90,7 +101,7 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
       version: this.state.vectorClock.get(this.config.nodeId) || 1,
       timestamp: Date.now(),
       originNodeId: this.config.nodeId,
-      ttl: this.config.maxRoundsToKeep,
+      ttl,
     };
 
     this.updates.set(update.key, update);
@@ -291,6 +302,13 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
   // ============================================================================
 
   async handleUpdate(update: GossipUpdate): Promise<void> {
+    // Hop budget exhausted: drop so flooding stays bounded in dense meshes
+    if (update.ttl <= 0) {
+      this.metrics.updatesDropped++;
+      this.emit('update_dropped', { key: update.key, originNodeId: update.originNodeId });
+      return;
+    }
+
     const existing = this.updates.get(update.key);
 
     if (existing) {
@@ -304,7 +322,7 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
           existingVersion: existing.version,
           incomingVersion: update.version,
         });
-        this.updates.set(update.key, { ...update, value: resolved });
+        this.updates.set(update.key, { ...update, value: resolved, ttl: update.ttl - 1 });
         this.metrics.conflictsDetected++;
         this.metrics.conflictsResolved++;
       } else {
@@ -498,3 +516,9 @@ export class GossipProtocol extends EventEmitter implements ConsensusProtocol {
     return { ...this.metrics };
   }
 }
+
+function assertValidTtl(ttl: number, name: string): void {
+  if (!Number.isInteger(ttl) || ttl < 0) {
+    throw new Error(`${name} must be a non-negative integer, got ${ttl}`);
+  }
+}
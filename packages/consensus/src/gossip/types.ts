@@ -18,6 +18,13 @@ export interface GossipConfig {
   antiEntropyIntervalMs: number;
   maxMessageAge: number;
   maxPendingMessages: number;
+  /** Initial hop count for locally proposed updates (defaults to maxRoundsToKeep) */
+  defaultTtl?: number;
+}
+
+export interface GossipProposeOptions {
+  /** Hop budget for this update, overriding defaultTtl */
+  ttl?: number;
 }
 
 // ============================================================================
@@ -59,6 +66,7 @@ export interface GossipUpdate<T = unknown> {
   version: number;
   timestamp: number;
   originNodeId: string;
+  /** Remaining hop count, decremented on every forward; dropped on arrival at 0 */
   ttl: number;
 }
 
@@ -139,6 +147,7 @@ export interface ConflictMetadata {
 export type GossipEventType =
   | 'update_received'
   | 'update_propagated'
+  | 'update_dropped'
   | 'conflict_detected'
   | 'conflict_resolved'
   | 'peer_joined'
@@ -164,6 +173,7 @@ export interface GossipMetrics {
   messagesReceived: number;
   updatesProposed: number;
   updatesPropagated: number;
+  updatesDropped: number;
   conflictsDetected: number;
   conflictsResolved: number;
   peersKnown: number;
@@ -48,6 +48,16 @@ export {
   type PBFTEventType,
   type PBFTEvent,
 } from './pbft/types.js';
+export {
+  FileWriteAheadLog,
+  InMemoryWriteAheadLog,
+  type WriteAheadLog,
+  type WalRecord,
+  type WalProposalRecord,
+  type WalVoteRecord,
+  type WalCommitRecord,
+  type WalViewChangeRecord,
+} from './pbft/wal.js';
 
 // Raft Consensus
 export { RaftConsensus } from './raft/RaftConsensus.js';
@@ -76,6 +86,7 @@ export {
 export { GossipProtocol } from './gossip/GossipProtocol.js';
 export {
   type GossipConfig,
+  type GossipProposeOptions,
   type GossipState,
   type PeerState,
   type GossipMessage,
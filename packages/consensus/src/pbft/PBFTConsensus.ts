@@ -21,6 +21,7 @@ import {
   type PBFTLogEntry,
   type PrePrepareMessage,
 } from './types.js';
+import type { WalRecord } from './wal.js';
 
 export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
   private config: PBFTConfig;
@@ -32,6 +33,8 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
   private votes: Map<string, Map<string, Vote>> = new Map();
   private suspectedByzantine: Set<string> = new Set();
   private viewChangeTimer: NodeJS.Timeout | null = null;
+  private stuckRoundTimer: NodeJS.Timeout | null = null;
+  private roundInFlight: { proposalId: string; startedAt: number } | null = null;
 
   constructor(config: PBFTConfig) {
     super();
@@ -47,6 +50,10 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
       lowWatermark: 0,
       highWatermark: config.watermarkWindow,
     };
+
+    if (config.wal) {
+      this.recover(config.wal.readAll());
+    }
   }
 
   // ============================================================================
@@ -76,6 +83,12 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
       throw new Error('Proposal not found');
     }
 
+    // Never contradict a vote we already cast, including one recovered from the WAL
+    const ownVote = this.votes.get(proposalId)?.get(this.config.nodeId);
+    if (ownVote && ownVote.vote !== vote) {
+      throw new Error('Refusing to equivocate: already voted on this proposal');
+    }
+
     await this.receiveVote({
       proposalId,
       nodeId: this.config.nodeId,
@@ -96,7 +109,11 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
 
     const entry = this.log.get(proposal.sequenceNumber);
     if (entry) {
+      this.config.wal?.append({ type: 'commit', proposalId, sequenceNumber: proposal.sequenceNumber });
       entry.isCommitted = true;
+      if (this.roundInFlight?.proposalId === proposalId) {
+        this.clearStuckRoundDetector();
+      }
       this.consensusState = ConsensusState.COMMITTED;
       this.state.phase = PBFTPhase.REPLY;
       this.emit('committed', { proposalId, sequenceNumber: proposal.sequenceNumber });
@@ -179,10 +196,22 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
       isExecuted: false,
     };
 
+    this.config.wal?.append({
+      type: 'proposal',
+      proposalId,
+      sequenceNumber: entry.sequenceNumber,
+      viewNumber: entry.viewNumber,
+      digest: entry.digest,
+      value,
+    });
+
     this.log.set(this.state.sequenceNumber, entry);
     this.proposals.set(proposalId, { value, sequenceNumber: this.state.sequenceNumber });
     this.votes.set(proposalId, new Map());
 
+    this.roundInFlight = { proposalId, startedAt: Date.now() };
+    this.armStuckRoundDetector();
+
     return proposalId;
   }
 
@@ -203,8 +232,20 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
       return;
     }
 
+    this.config.wal?.append({
+      type: 'vote',
+      proposalId: vote.proposalId,
+      nodeId: vote.nodeId,
+      vote: vote.vote,
+      timestamp: vote.timestamp,
+    });
     proposalVotes.set(vote.nodeId, vote);
 
+    // A new vote is progress; restart the stall window
+    if (this.roundInFlight?.proposalId === vote.proposalId) {
+      this.armStuckRoundDetector();
+    }
+
     // Check if we've reached quorum for prepare phase
     if (this.hasQuorum(vote.proposalId) && this.state.phase === PBFTPhase.PREPARE) {
       this.state.phase = PBFTPhase.COMMIT;
@@ -265,6 +306,7 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
   }
 
   async initiateViewChange(): Promise<void> {
+    this.config.wal?.append({ type: 'view-change', viewNumber: this.state.viewNumber + 1 });
     this.state.viewNumber++;
     this.state.primaryId = this.calculatePrimary(this.state.viewNumber);
     this.state.phase = PBFTPhase.VIEW_CHANGE;
@@ -275,6 +317,65 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
     });
   }
 
+  // ============================================================================
+  // Stuck Round Detection
+  // ============================================================================
+
+  private armStuckRoundDetector(): void {
+    if (!this.config.stuckRoundTimeoutMs) return;
+
+    if (this.stuckRoundTimer) {
+      clearTimeout(this.stuckRoundTimer);
+    }
+
+    this.stuckRoundTimer = setTimeout(() => {
+      this.stuckRoundTimer = null;
+      this.handleStuckRound();
+    }, this.config.stuckRoundTimeoutMs);
+  }
+
+  private clearStuckRoundDetector(): void {
+    if (this.stuckRoundTimer) {
+      clearTimeout(this.stuckRoundTimer);
+      this.stuckRoundTimer = null;
+    }
+    this.roundInFlight = null;
+  }
+
+  /**
+   * Fired when a round makes no vote progress for stuckRoundTimeoutMs.
+   * If quorum is still missing, the round is abandoned and a view change is initiated
+   * proactively instead of waiting for the primary timeout.
+   */
+  private handleStuckRound(): void {
+    const round = this.roundInFlight;
+    if (!round) return;
+
+    const proposal = this.proposals.get(round.proposalId);
+    const votes = this.getVoteCount(round.proposalId);
+    const quorum = this.getQuorumSize();
+
+    if (!proposal || this.isCommitted(round.proposalId) || votes >= quorum) {
+      this.roundInFlight = null;
+      return;
+    }
+
+    this.roundInFlight = null;
+    const stalledMs = Date.now() - round.startedAt;
+
+    this.emit('stuck_round_detected', {
+      proposalId: round.proposalId,
+      sequenceNumber: proposal.sequenceNumber,
+      viewNumber: this.state.viewNumber,
+      votes,
+      quorum,
+      stalledMs,
+      cause: `insufficient votes for quorum (${votes}/${quorum}) after ${stalledMs}ms`,
+    });
+
+    void this.initiateViewChange();
+  }
+
   // ============================================================================
   // Message Handling
   // ============================================================================
@@ -309,6 +410,69 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
     return this.computeDigest(data) === digest;
   }
 
+  // ============================================================================
+  // Crash Recovery
+  // ============================================================================
+
+  /**
+   * Rebuild proposals, votes and commit state from WAL records
+   */
+  private recover(records: WalRecord[]): void {
+    for (const record of records) {
+      switch (record.type) {
+        case 'proposal': {
+          this.log.set(record.sequenceNumber, {
+            sequenceNumber: record.sequenceNumber,
+            viewNumber: record.viewNumber,
+            digest: record.digest,
+            request: record.value,
+            prePrepare: null,
+            prepares: new Map(),
+            commits: new Map(),
+            isPrepared: false,
+            isCommitted: false,
+            isExecuted: false,
+          });
+          this.proposals.set(record.proposalId, {
+            value: record.value,
+            sequenceNumber: record.sequenceNumber,
+          });
+          if (!this.votes.has(record.proposalId)) {
+            this.votes.set(record.proposalId, new Map());
+          }
+          this.state.sequenceNumber = Math.max(this.state.sequenceNumber, record.sequenceNumber);
+          break;
+        }
+        case 'vote': {
+          this.votes.get(record.proposalId)?.set(record.nodeId, {
+            proposalId: record.proposalId,
+            nodeId: record.nodeId,
+            vote: record.vote,
+            timestamp: record.timestamp,
+          });
+          break;
+        }
+        case 'commit': {
+          const entry = this.log.get(record.sequenceNumber);
+          if (entry) {
+            entry.isCommitted = true;
+          }
+          break;
+        }
+        case 'view-change': {
+          this.state.viewNumber = Math.max(this.state.viewNumber, record.viewNumber);
+          this.state.primaryId = this.calculatePrimary(this.state.viewNumber);
+          break;
+        }
+      }
+    }
+  }
+
+  isCommitted(proposalId: string): boolean {
+    const proposal = this.proposals.get(proposalId);
+    return proposal ? this.log.get(proposal.sequenceNumber)?.isCommitted ?? false : false;
+  }
+
   // ============================================================================
   // Helper Methods
   // ============================================================================
@@ -344,5 +508,6 @@ export class PBFTConsensus extends EventEmitter implements ConsensusProtocol {
       clearTimeout(this.viewChangeTimer);
       this.viewChangeTimer = null;
     }
+    this.clearStuckRoundDetector();
   }
 }
@@ -4,6 +4,7 @@
  */
 
 import type { ConsensusNode, SignedMessage } from '../types.js';
+import type { WriteAheadLog } from './wal.js';
 
 // ============================================================================
 // PBFT Configuration
@@ -16,6 +17,10 @@ export interface PBFTConfig {
   requestTimeoutMs: number;
   checkpointInterval: number;
   watermarkWindow: number;
+  /** Optional write-ahead log; when set, protocol state is recovered from it on startup */
+  wal?: WriteAheadLog;
+  /** Stall duration without vote progress before a round is declared stuck and a view change starts (disabled if unset) */
+  stuckRoundTimeoutMs?: number;
 }
 
 // ============================================================================
@@ -168,6 +173,7 @@ export type PBFTEventType =
   | 'executed'
   | 'view_change_started'
   | 'view_change_completed'
+  | 'stuck_round_detected'
   | 'checkpoint_created'
   | 'byzantine_detected';
 
@@ -0,0 +1,152 @@
+/**
+ * Write-ahead log for PBFT protocol state
+ * Persists proposals, votes and commits before they are applied so a restarted
+ * node recovers its prior votes and never contradicts them
+ */
+
+import {
+  appendFileSync,
+  existsSync,
+  mkdirSync,
+  readFileSync,
+  truncateSync,
+  openSync,
+  fsyncSync,
+  closeSync,
+} from 'fs';
+import { dirname } from 'path';
+
+// ============================================================================
+// WAL Records
+// ============================================================================
+
+export interface WalProposalRecord {
+  type: 'proposal';
+  proposalId: string;
+  sequenceNumber: number;
+  viewNumber: number;
+  digest: string;
+  value: unknown;
+}
+
+export interface WalVoteRecord {
+  type: 'vote';
+  proposalId: string;
+  nodeId: string;
+  vote: boolean;
+  timestamp: number;
+}
+
+export interface WalCommitRecord {
+  type: 'commit';
+  proposalId: string;
+  sequenceNumber: number;
+}
+
+export interface WalViewChangeRecord {
+  type: 'view-change';
+  viewNumber: number;
+}
+
+export type WalRecord =
+  | WalProposalRecord
+  | WalVoteRecord
+  | WalCommitRecord
+  | WalViewChangeRecord;
+
+export interface WriteAheadLog {
+  /** Durably append a record; must not return before the record is persisted */
+  append(record: WalRecord): void;
+  /** Read all records in append order */
+  readAll(): WalRecord[];
+}
+
+// ============================================================================
+// File-backed WAL
+// ============================================================================
+
+/**
+ * Append-only JSON-lines WAL, fsynced on every append
+ * A torn final line (crash mid-write) is truncated on open so later appends
+ * start on a fresh line
+ */
+export class FileWriteAheadLog implements WriteAheadLog {
+  private path: string;
+
+  constructor(path: string) {
+    this.path = path;
+    mkdirSync(dirname(path), { recursive: true });
+    this.truncateTornTail();
+  }
+
+  append(record: WalRecord): void {
+    appendFileSync(this.path, JSON.stringify(record) + '\n');
+    const fd = openSync(this.path, 'r');
+    try {
+      fsyncSync(fd);
+    } finally {
+      closeSync(fd);
+    }
+  }
+
+  readAll(): WalRecord[] {
+    if (!existsSync(this.path)) {
+      return [];
+    }
+
+    const records: WalRecord[] = [];
+    const lines = readFileSync(this.path, 'utf8').split('\n');
+
+    for (const line of lines) {
+      if (!line.trim()) continue;
+      try {
+        records.push(JSON.parse(line) as WalRecord);
+      } catch {
+        // Torn write from a crash; everything before it is intact
+        break;
+      }
+    }
+
+    return records;
+  }
+
+  /**
+   * Drop any bytes after the last complete record so the next append does not
+   * land on the same line as a half-written one
+   */
+  private truncateTornTail(): void {
+    if (!existsSync(this.path)) {
+      return;
+    }
+
+    const data = readFileSync(this.path);
+    const end = data.lastIndexOf(0x0a) + 1;
+    if (end === data.length) {
+      return;
+    }
+
+    truncateSync(this.path, end);
+    const fd = openSync(this.path, 'r+');
+    try {
+      fsyncSync(fd);
+    } finally {
+      closeSync(fd);
+    }
+  }
+}
+
+// ============================================================================
+// In-memory WAL (testing)
+// ============================================================================
+
+export class InMemoryWriteAheadLog implements WriteAheadLog {
+  private records: WalRecord[] = [];
+
+  append(record: WalRecord): void {
+    this.records.push(structuredClone(record));
+  }
+
+  readAll(): WalRecord[] {
+    return this.records.map(r => structuredClone(r));
+  }
+}
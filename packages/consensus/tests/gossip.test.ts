@@ -248,6 +248,79 @@ describe('GossipProtocol', () => {
     });
   });
 
+  describe('hop-count TTL', () => {
+    it('should drop updates that arrive with TTL 0', async () => {
+      await gossip.handleUpdate({
+        key: 'expired',
+        value: 'value',
+        version: 1,
+        timestamp: Date.now(),
+        originNodeId: 'node-1',
+        ttl: 0,
+      });
+
+      expect(gossip.getUpdate('expired')).toBeUndefined();
+      expect(gossip.getMetrics().updatesDropped).toBe(1);
+    });
+
+    it('should let the origin set the initial TTL', async () => {
+      await gossip.propose({ key: 'short-lived', value: 'data' }, { ttl: 2 });
+
+      expect(gossip.getUpdate('short-lived')?.ttl).toBe(2);
+    });
+
+    it('should not treat an application ttl field as the hop budget', async () => {
+      const withDefault = new GossipProtocol({ ...config, defaultTtl: 3 });
+
+      await withDefault.propose({ key: 'session', value: { ttl: 3600 }, ttl: 3600 });
+
+      expect(withDefault.getUpdate('session')?.ttl).toBe(3);
+    });
+
+    it('should reject invalid TTL values', async () => {
+      await expect(gossip.propose({ key: 'bad' }, { ttl: -1 })).rejects.toThrow('non-negative integer');
+      await expect(gossip.propose({ key: 'bad' }, { ttl: 1.5 })).rejects.toThrow('non-negative integer');
+      expect(() => new GossipProtocol({ ...config, defaultTtl: -2 })).toThrow('defaultTtl');
+      expect(() => new GossipProtocol({ ...config, defaultTtl: NaN })).toThrow('defaultTtl');
+    });
+
+    it('should reach peers up to TTL hops away but no further', async () => {
+      // Line topology: node-0 - node-1 - node-2 - node-3
+      const ids = ['node-0', 'node-1', 'node-2', 'node-3'];
+      const peers = new Map<string, GossipProtocol>();
+
+      ids.forEach((id, i) => {
+        const neighbours = ids.filter((_, j) => Math.abs(i - j) <= 1);
+        peers.set(id, new GossipProtocol({
+          ...config,
+          nodeId: id,
+          nodes: nodes.filter(n => neighbours.includes(n.id)),
+          fanout: 2,
+        }));
+      });
+
+      const inFlight: Promise<unknown>[] = [];
+      for (const peer of peers.values()) {
+        peer.on('gossip_sent', ({ targetId, message }) => {
+          inFlight.push(peers.get(targetId)!.handleGossipMessage(message));
+        });
+      }
+
+      await peers.get('node-0')!.propose({ key: 'bounded', value: 'data' }, { ttl: 2 });
+
+      for (let round = 0; round < 4; round++) {
+        for (const peer of peers.values()) {
+          await peer.gossipRound();
+        }
+        await Promise.all(inFlight.splice(0));
+      }
+
+      expect(peers.get('node-1')!.getValue('bounded')).toBe('data');
+      expect(peers.get('node-2')!.getValue('bounded')).toBe('data');
+      expect(peers.get('node-3')!.getValue('bounded')).toBeUndefined();
+    });
+  });
+
   describe('message handling', () => {
     it('should handle push message', async () => {
       const message: GossipMessage = {
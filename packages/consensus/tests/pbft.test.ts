@@ -304,6 +304,67 @@ describe('PBFTConsensus', () => {
     });
   });
 
+  describe('stuck round detection', () => {
+    beforeEach(() => {
+      vi.useFakeTimers();
+      pbft = new PBFTConsensus({ ...config, stuckRoundTimeoutMs: 1000 });
+    });
+
+    afterEach(() => {
+      pbft.stop();
+      vi.useRealTimers();
+    });
+
+    it('should initiate view change when quorum is not reached in time', async () => {
+      const detected = vi.fn();
+      pbft.on('stuck_round_detected', detected);
+
+      const proposalId = await pbft.createProposal({ data: 'test' });
+
+      // Votes from node-2 and node-3 are dropped; only 2 of 3 required arrive
+      await pbft.receiveVote({ proposalId, nodeId: 'node-0', vote: true, timestamp: Date.now() });
+      await pbft.receiveVote({ proposalId, nodeId: 'node-1', vote: true, timestamp: Date.now() });
+
+      vi.advanceTimersByTime(500);
+      expect(detected).not.toHaveBeenCalled();
+
+      vi.advanceTimersByTime(600);
+      expect(detected).toHaveBeenCalledWith(
+        expect.objectContaining({ proposalId, votes: 2, quorum: 3 })
+      );
+      expect(pbft.getViewNumber()).toBe(1);
+    });
+
+    it('should restart the stall window on vote progress', async () => {
+      const detected = vi.fn();
+      pbft.on('stuck_round_detected', detected);
+
+      const proposalId = await pbft.createProposal({ data: 'test' });
+
+      vi.advanceTimersByTime(800);
+      await pbft.receiveVote({ proposalId, nodeId: 'node-0', vote: true, timestamp: Date.now() });
+      vi.advanceTimersByTime(800);
+
+      expect(detected).not.toHaveBeenCalled();
+    });
+
+    it('should not fire once the round commits', async () => {
+      const detected = vi.fn();
+      pbft.on('stuck_round_detected', detected);
+
+      const proposalId = await pbft.createProposal({ data: 'test' });
+      await pbft.receiveVote({ proposalId, nodeId: 'node-0', vote: true, timestamp: Date.now() });
+      await pbft.receiveVote({ proposalId, nodeId: 'node-1', vote: true, timestamp: Date.now() });
+      await pbft.receiveVote({ proposalId, nodeId: 'node-2', vote: true, timestamp: Date.now() });
+      await pbft.commit(proposalId);
+
+      vi.advanceTimersByTime(5000);
+
+      expect(detected).not.toHaveBeenCalled();
+      expect(pbft.getViewNumber()).toBe(0);
+    });
+  });
+
   describe('message digest verification', () => {
     it('should compute deterministic digest for same data', () => {
       const data = { action: 'transfer', amount: 1000 };